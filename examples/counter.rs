@@ -62,7 +62,7 @@ fn main() -> Result<(), eframe::Error> {
         ..Default::default()
     };
 
-    eframe::run_native("button", options, Box::new(|cc| Ok(Box::new(App::new()))))?;
+    eframe::run_native("button", options, Box::new(|_cc| Ok(Box::new(App::new()))))?;
 
     Ok(())
 }
@@ -36,7 +36,7 @@ fn main() -> Result<(), eframe::Error> {
         ..Default::default()
     };
 
-    eframe::run_native("simple_button", options, Box::new(|cc| Ok(Box::new(App::new()))))?;
+    eframe::run_native("simple_button", options, Box::new(|_cc| Ok(Box::new(App::new()))))?;
 
     Ok(())
 }
@@ -1,8 +1,8 @@
 use eframe::epaint::Margin;
-use egui::{CentralPanel, Context, Id, RichText, TextEdit};
-use egui_layout::layout::{Alignment, Draw, Layout, LayoutDirection, LayoutParams, Measure};
+use egui::{CentralPanel, Context};
+use egui_layout::ext::UiLayoutExt;
+use egui_layout::layout::{Alignment, Layout, LayoutDirection, LayoutParams, MainAxisAlignment};
 use egui_layout::widgets::frame::Frame;
-use egui_layout::widgets::lazy::LazyMeasuredWidget;
 use egui_layout::widgets::text::Text;
 
 struct App;
@@ -16,16 +16,16 @@ impl eframe::App for App {
                 ..Default::default()
             })
             .show(ctx, |ui| {
-                Layout::new(LayoutParams {
-                    direction: LayoutDirection::Column,
-                    main_axis_alignment: Alignment::Center,
-                    cross_axis_alignment: Alignment::Center,
-                })
-                    // .with_child(Text::new(RichText::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est laborum.")))
-                    .with_child(Text::new("short"))
-                    .measure(ui.available_size(), ui)
-                    .1
-                    .draw(ui.available_rect_before_wrap(), ui);
+                // The frame fills the panel, so the column centres its child within all of it.
+                ui.show_layout(Frame::new(
+                    Layout::new(LayoutParams {
+                        direction: LayoutDirection::Column,
+                        main_axis_alignment: MainAxisAlignment::Center,
+                        cross_axis_alignment: Alignment::Center,
+                        ..Default::default()
+                    })
+                    .with_child(Text::new("short")),
+                ));
             });
     }
 }
@@ -35,7 +35,7 @@ fn main() -> Result<(), eframe::Error> {
         ..Default::default()
     };
 
-    eframe::run_native("text", options, Box::new(|cc| Ok(Box::new(App))))?;
+    eframe::run_native("text", options, Box::new(|_cc| Ok(Box::new(App))))?;
 
     Ok(())
 }
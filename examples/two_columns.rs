@@ -1,13 +1,13 @@
 use eframe::epaint::Margin;
-use egui::{CentralPanel, Context, Id, RichText, TextEdit};
-use egui_layout::layout::{Alignment, Draw, Layout, LayoutDirection, LayoutParams, Measure};
+use egui::{CentralPanel, Context, Id, RichText};
+use egui_layout::ext::UiLayoutExt;
+use egui_layout::layout::{Alignment, Flex, MainAxisAlignment};
+use egui_layout::widgets::eager::EagerMeasuredWidget;
 use egui_layout::widgets::frame::Frame;
-use egui_layout::widgets::lazy::LazyMeasuredWidget;
 use egui_layout::widgets::text::Text;
+use egui_layout::{column, row};
 
-struct App {
-    address: String,
-}
+struct App;
 
 impl eframe::App for App {
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
@@ -18,53 +18,31 @@ impl eframe::App for App {
                 ..Default::default()
             })
             .show(ctx, |ui| {
-                Layout::new(LayoutParams {
-                    direction: LayoutDirection::Column,
-                    main_axis_alignment: Alignment::Start,
-                    cross_axis_alignment: Alignment::Center,
-                })
-                .with_child(Text::new(RichText::from("extern_traces").heading()))
-                .with_child(Text::new({
-                    let version = env!("CARGO_PKG_VERSION");
-                    let git_short_sha = "7642d77";
+                ui.show_layout(column![
+                    main_axis_alignment: MainAxisAlignment::Start,
+                    cross_axis_alignment: Alignment::Center;
+                    Text::new(RichText::from("extern_traces").heading()),
+                    Text::new({
+                        let version = env!("CARGO_PKG_VERSION");
+                        let git_short_sha = "7642d77";
 
-                    format!("v{version} @ {git_short_sha}")
-                }))
-                .with_child(Frame::new(
-                    Layout::new(LayoutParams {
-                        direction: LayoutDirection::Row,
-                        main_axis_alignment: Alignment::Center,
-                        cross_axis_alignment: Alignment::Center,
-                        ..Default::default()
-                    })
-                    .with_child(Frame::new(
-                        Layout::new(LayoutParams {
-                            direction: LayoutDirection::Column,
-                            main_axis_alignment: Alignment::Start,
-                            ..Default::default()
-                        })
-                        .with_child(Text::new(RichText::from("load traces")))
-                        .with_child(LazyMeasuredWidget::new(
-                            egui::Button::new("open"),
-                            Id::new("open"),
-                        )),
-                    ))
-                    .with_child(Frame::new(
-                        Layout::new(LayoutParams {
-                            direction: LayoutDirection::Column,
-                            main_axis_alignment: Alignment::Start,
-                            ..Default::default()
-                        })
-                        .with_child(Text::new(RichText::from("listen for traces")))
-                        .with_child(LazyMeasuredWidget::new(
-                            egui::Button::new("listen"),
-                            Id::new("listen"),
-                        )),
-                    )),
-                ))
-                .measure(ui.available_size(), ui)
-                .1
-                .draw(ui.available_rect_before_wrap(), ui);
+                        format!("v{version} @ {git_short_sha}")
+                    }),
+                    Flex::grow(1.) => Frame::new(row![
+                        main_axis_alignment: MainAxisAlignment::Center,
+                        cross_axis_alignment: Alignment::Center;
+                        Flex::grow(1.) => Frame::new(column![
+                            main_axis_alignment: MainAxisAlignment::Start;
+                            Text::new(RichText::from("load traces")),
+                            EagerMeasuredWidget::new(|ui| ui.button("open"), Id::new("open")),
+                        ]),
+                        Flex::grow(1.) => Frame::new(column![
+                            main_axis_alignment: MainAxisAlignment::Start;
+                            Text::new(RichText::from("listen for traces")),
+                            EagerMeasuredWidget::new(|ui| ui.button("listen"), Id::new("listen")),
+                        ]),
+                    ]),
+                ]);
             });
     }
 }
@@ -74,15 +52,7 @@ fn main() -> Result<(), eframe::Error> {
         ..Default::default()
     };
 
-    eframe::run_native(
-        "extern_traces",
-        options,
-        Box::new(|cc| {
-            Ok(Box::new(App {
-                address: "".to_string(),
-            }))
-        }),
-    )?;
+    eframe::run_native("extern_traces", options, Box::new(|_cc| Ok(Box::new(App))))?;
 
     Ok(())
 }
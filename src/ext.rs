@@ -0,0 +1,19 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Response, Ui, UiBuilder};
+
+pub trait UiLayoutExt {
+    /// Measures `root` against the available space, allocates the measured size and draws `root`
+    /// into it.
+    fn show_layout(&mut self, root: impl Measure) -> Response;
+}
+
+impl UiLayoutExt for Ui {
+    fn show_layout(&mut self, root: impl Measure) -> Response {
+        let (size, measured) = root.measure(Constraints::loose(self.available_size()), self);
+        let (_, rect) = self.allocate_space(size);
+
+        // Draw into a child so widgets allocating their region don't advance our cursor again.
+        let mut child_ui = self.new_child(UiBuilder::new().max_rect(rect).layout(*self.layout()));
+        measured.draw(rect, &mut child_ui)
+    }
+}
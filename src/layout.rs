@@ -1,30 +1,125 @@
-use egui::{Rect, Ui, Vec2, vec2};
+use egui::{Id, Rect, Response, Sense, Ui, Vec2};
 
 pub trait Draw {
-    fn draw(self, region: Rect, ui: &mut Ui);
+    /// Paints into `region` and returns the response of the drawn content, which for containers
+    /// is the union of their own response with those of their children. The response of a single
+    /// node is reported by wrapping it in a [`Tracked`](crate::widgets::tracked::Tracked).
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response;
+
+    /// Distance from the top of the measured size to the first baseline, for content which has
+    /// one.
+    fn baseline(&self) -> Option<f32> {
+        None
+    }
 }
 
-trait DrawDyn {
-    fn draw(self: Box<Self>, region: Rect, ui: &mut Ui);
+pub(crate) trait DrawDyn {
+    fn draw(self: Box<Self>, region: Rect, ui: &mut Ui) -> Response;
+
+    fn baseline(&self) -> Option<f32>;
 }
 
 impl<T> DrawDyn for T
 where
     T: Draw,
 {
-    fn draw(self: Box<Self>, region: Rect, ui: &mut Ui) {
-        (*self).draw(region, ui);
+    fn draw(self: Box<Self>, region: Rect, ui: &mut Ui) -> Response {
+        (*self).draw(region, ui)
+    }
+
+    fn baseline(&self) -> Option<f32> {
+        Draw::baseline(self)
+    }
+}
+
+/// The range of sizes a widget may pick from when it is measured. A widget is expected to return
+/// a size within these bounds, either axis of `max` may be infinite.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl Constraints {
+    pub const UNBOUNDED: Constraints = Constraints {
+        min: Vec2::ZERO,
+        max: Vec2::INFINITY,
+    };
+
+    pub fn new(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    /// Allows exactly one size.
+    pub fn tight(size: Vec2) -> Self {
+        Self {
+            min: size,
+            max: size,
+        }
+    }
+
+    /// Allows any size up to `max`.
+    pub fn loose(max: Vec2) -> Self {
+        Self {
+            min: Vec2::ZERO,
+            max,
+        }
+    }
+
+    pub fn is_tight(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// Removes the minimum size, keeping the maximum.
+    pub fn loosen(self) -> Self {
+        Self::loose(self.max)
+    }
+
+    /// Clamps `size` into these constraints.
+    pub fn constrain(&self, size: Vec2) -> Vec2 {
+        size.max(self.min).min(self.max)
+    }
+
+    /// Clamps these constraints into `outer`, so the result satisfies both where possible and
+    /// `outer` otherwise.
+    pub fn enforce(self, outer: Constraints) -> Self {
+        Self {
+            min: self.min.clamp(outer.min, outer.max),
+            max: self.max.clamp(outer.min, outer.max),
+        }
+    }
+
+    /// Shrinks both bounds by `amount`, used for content inset by margins.
+    pub fn deflate(self, amount: Vec2) -> Self {
+        Self {
+            min: (self.min - amount).max(Vec2::ZERO),
+            max: (self.max - amount).max(Vec2::ZERO),
+        }
     }
 }
 
 pub trait Measure {
     type Measured: Draw;
 
-    fn measure(self, max_size: Vec2, ui: &Ui) -> (Vec2, Self::Measured);
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured);
+
+    /// How the widget grows and shrinks along the main axis when it's the child of a layout.
+    fn flex(&self) -> Flex {
+        Flex::default()
+    }
+
+    /// Narrowest width the widget can be measured at without overflowing, such as the longest
+    /// word of a text. `None` when the widget can't tell without being measured.
+    fn min_content_width(&self, _ui: &Ui) -> Option<f32> {
+        None
+    }
 }
 
-trait MeasureDyn<'a> {
-    fn measure(self: Box<Self>, max_size: Vec2, ui: &Ui) -> (Vec2, Box<dyn DrawDyn + 'a>);
+pub(crate) trait MeasureDyn<'a> {
+    fn measure(self: Box<Self>, constraints: Constraints, ui: &Ui)
+    -> (Vec2, Box<dyn DrawDyn + 'a>);
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32>;
 }
 
 impl<'a, T> MeasureDyn<'a> for T
@@ -32,35 +127,88 @@ where
     <T as Measure>::Measured: 'a,
     T: Measure,
 {
-    fn measure(self: Box<Self>, max_size: Vec2, ui: &Ui) -> (Vec2, Box<dyn DrawDyn + 'a>) {
-        let (vec, measured) = (*self).measure(max_size, ui);
+    fn measure(
+        self: Box<Self>,
+        constraints: Constraints,
+        ui: &Ui,
+    ) -> (Vec2, Box<dyn DrawDyn + 'a>) {
+        let (vec, measured) = (*self).measure(constraints, ui);
         (vec, Box::new(measured))
     }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        Measure::min_content_width(self, ui)
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum LayoutDirection {
     Row,
     Column,
+    /// Like [`LayoutDirection::Row`], but children are placed starting from the end of the
+    /// main axis, so the first child ends up last.
+    RowReverse,
+    /// Like [`LayoutDirection::Column`], but children are placed starting from the end of the
+    /// main axis, so the first child ends up last.
+    ColumnReverse,
 }
 
 impl LayoutDirection {
     fn main_axis(&self, vec2: Vec2) -> f32 {
-        match self {
-            LayoutDirection::Row => vec2.x,
-            LayoutDirection::Column => vec2.y,
-        }
+        vec2.main_axis(*self)
     }
 
     fn cross_axis(&self, vec2: Vec2) -> f32 {
-        match self {
-            LayoutDirection::Row => vec2.y,
-            LayoutDirection::Column => vec2.x,
+        vec2.cross_axis(*self)
+    }
+
+    pub(crate) fn is_horizontal(&self) -> bool {
+        matches!(self, LayoutDirection::Row | LayoutDirection::RowReverse)
+    }
+
+    pub(crate) fn is_reversed(&self) -> bool {
+        matches!(
+            self,
+            LayoutDirection::RowReverse | LayoutDirection::ColumnReverse
+        )
+    }
+
+    /// Converts a main axis offset counted from the start of the placement order into one counted
+    /// from the start of `extent`, mirroring it for reversed directions and for rows laid out
+    /// right to left.
+    pub(crate) fn place_main_axis(
+        &self,
+        text_direction: TextDirection,
+        extent: f32,
+        offset: f32,
+        size: f32,
+    ) -> f32 {
+        let right_to_left = self.is_horizontal() && text_direction.is_right_to_left();
+
+        if self.is_reversed() != right_to_left {
+            extent - offset - size
+        } else {
+            offset
+        }
+    }
+
+    /// Mirrors a cross axis offset of columns laid out right to left.
+    pub(crate) fn place_cross_axis(
+        &self,
+        text_direction: TextDirection,
+        extent: f32,
+        offset: f32,
+        size: f32,
+    ) -> f32 {
+        if !self.is_horizontal() && text_direction.is_right_to_left() {
+            extent - offset - size
+        } else {
+            offset
         }
     }
 }
 
-trait Vec2Ext {
+pub(crate) trait Vec2Ext {
     fn with_main_axis(self, direction: LayoutDirection, value: f32) -> Vec2;
     fn with_cross_axis(self, direction: LayoutDirection, value: f32) -> Vec2;
     fn main_axis(self, direction: LayoutDirection) -> f32;
@@ -69,30 +217,34 @@ trait Vec2Ext {
 
 impl Vec2Ext for Vec2 {
     fn with_main_axis(self, direction: LayoutDirection, value: f32) -> Vec2 {
-        match direction {
-            LayoutDirection::Row => Vec2::new(value, self.y),
-            LayoutDirection::Column => Vec2::new(self.x, value),
+        if direction.is_horizontal() {
+            Vec2::new(value, self.y)
+        } else {
+            Vec2::new(self.x, value)
         }
     }
 
     fn with_cross_axis(self, direction: LayoutDirection, value: f32) -> Vec2 {
-        match direction {
-            LayoutDirection::Row => Vec2::new(self.x, value),
-            LayoutDirection::Column => Vec2::new(value, self.y),
+        if direction.is_horizontal() {
+            Vec2::new(self.x, value)
+        } else {
+            Vec2::new(value, self.y)
         }
     }
 
     fn main_axis(self, direction: LayoutDirection) -> f32 {
-        match direction {
-            LayoutDirection::Row => self.x,
-            LayoutDirection::Column => self.y,
+        if direction.is_horizontal() {
+            self.x
+        } else {
+            self.y
         }
     }
 
     fn cross_axis(self, direction: LayoutDirection) -> f32 {
-        match direction {
-            LayoutDirection::Row => self.y,
-            LayoutDirection::Column => self.x,
+        if direction.is_horizontal() {
+            self.y
+        } else {
+            self.x
         }
     }
 }
@@ -101,27 +253,223 @@ pub enum Alignment {
     Start,
     End,
     Center,
+    /// Sizes children to the cross axis extent of the layout, which is that of the largest child
+    /// unless the layout is given more by its constraints.
+    Stretch,
+    /// Lines up the first baselines of children in a row. Children without a baseline, and all
+    /// children of a column, are placed at the start.
+    Baseline,
+}
+
+pub enum MainAxisAlignment {
+    Start,
+    End,
+    Center,
+    /// Free space is placed between children, with none before the first or after the last.
+    SpaceBetween,
+    /// Free space is placed around each child, so the outer gaps are half the inner ones.
+    SpaceAround,
+    /// Free space is split evenly between children and both ends.
+    SpaceEvenly,
+}
+
+impl MainAxisAlignment {
+    /// Returns the offset of the first child and the extra space inserted between each pair of
+    /// children.
+    pub(crate) fn distribute(&self, free_space: f32, children: usize) -> (f32, f32) {
+        if children == 0 {
+            return (0., 0.);
+        }
+
+        let children = children as f32;
+        let distributed_space = free_space.max(0.);
+
+        match self {
+            MainAxisAlignment::Start => (0., 0.),
+            MainAxisAlignment::End => (free_space, 0.),
+            MainAxisAlignment::Center => (free_space / 2.0, 0.),
+            MainAxisAlignment::SpaceBetween if children > 1. => {
+                (0., distributed_space / (children - 1.))
+            }
+            MainAxisAlignment::SpaceBetween => (0., 0.),
+            MainAxisAlignment::SpaceAround => {
+                let around = distributed_space / children;
+                (around / 2.0, around)
+            }
+            MainAxisAlignment::SpaceEvenly => {
+                let between = distributed_space / (children + 1.);
+                (between, between)
+            }
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum TextDirection {
+    LeftToRight,
+    /// Mirrors the horizontal axis, so `Start` refers to the right edge.
+    RightToLeft,
+}
+
+/// Key of the tag recording the direction of the `Ui`s layouts create for their children.
+const TEXT_DIRECTION_TAG: &str = "egui_layout::text_direction";
+
+impl TextDirection {
+    /// Picks up the direction of the enclosing layout, defaulting to left to right. The
+    /// alignment of egui's own layouts isn't taken as a direction, as those are often only used
+    /// to right align content.
+    pub fn from_ui(ui: &Ui) -> Self {
+        ui.stack()
+            .iter()
+            .find_map(|it| {
+                it.tags()
+                    .get_downcast::<TextDirection>(TEXT_DIRECTION_TAG)
+                    .copied()
+            })
+            .unwrap_or(TextDirection::LeftToRight)
+    }
+
+    /// Creates an invisible `Ui` in which content measures as if it were drawn within a layout of
+    /// this direction.
+    fn measure_ui(&self, ui: &Ui) -> Ui {
+        Ui::new(
+            ui.ctx().clone(),
+            ui.id().with(TEXT_DIRECTION_TAG).with(self),
+            self.ui_builder()
+                .layer_id(ui.layer_id())
+                .max_rect(ui.max_rect())
+                .style(ui.style().clone())
+                .sizing_pass()
+                .invisible(),
+        )
+    }
+
+    /// Builds a child `Ui` which inherits this direction.
+    pub(crate) fn ui_builder(&self) -> egui::UiBuilder {
+        let layout = match self {
+            TextDirection::LeftToRight => egui::Layout::top_down(egui::Align::Min),
+            TextDirection::RightToLeft => egui::Layout::top_down(egui::Align::Max),
+        };
+
+        egui::UiBuilder::new()
+            .layout(layout)
+            .ui_stack_info(egui::UiStackInfo::default().with_tag_value(TEXT_DIRECTION_TAG, *self))
+    }
+
+    pub(crate) fn is_right_to_left(&self) -> bool {
+        *self == TextDirection::RightToLeft
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Overflow {
+    /// Children are painted outside of the region.
+    Visible,
+    /// Children are clipped to the region.
+    Clip,
+    /// Children are placed in a scroll area along the main axis.
+    Scroll,
 }
 
 pub struct LayoutParams {
     pub direction: LayoutDirection,
-    pub main_axis_alignment: Alignment,
+    pub main_axis_alignment: MainAxisAlignment,
     pub cross_axis_alignment: Alignment,
+    /// Main axis space between adjacent children. Defaults to the style's `item_spacing`.
+    pub gap: Option<f32>,
+    /// Defaults to the direction of the enclosing layout, or left to right outside of one.
+    pub text_direction: Option<TextDirection>,
+    /// What happens to children which don't fit into the region the layout is drawn in.
+    pub overflow: Overflow,
+    /// Salt of the scroll area of [`Overflow::Scroll`], which keeps the scroll position stable
+    /// when the surrounding tree changes shape. Defaults to one based on the layout's position.
+    pub id_salt: Option<Id>,
 }
 
 impl Default for LayoutParams {
     fn default() -> Self {
         LayoutParams {
             direction: LayoutDirection::Row,
-            main_axis_alignment: Alignment::Center,
+            main_axis_alignment: MainAxisAlignment::Center,
             cross_axis_alignment: Alignment::Start,
+            gap: None,
+            text_direction: None,
+            overflow: Overflow::Visible,
+            id_salt: None,
+        }
+    }
+}
+
+/// Flex factors of a layout child. The default neither grows nor shrinks the child.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Flex {
+    pub grow: f32,
+    /// Only applies to children with a `basis`, as children are measured once and those without
+    /// one are measured at their content size.
+    pub shrink: f32,
+    /// Main axis size before free space is distributed. `None` sizes children without `grow` to
+    /// their content and starts growing children from zero.
+    pub basis: Option<f32>,
+}
+
+impl Flex {
+    pub fn grow(grow: f32) -> Self {
+        Flex {
+            grow,
+            ..Default::default()
+        }
+    }
+
+    pub fn fixed(basis: f32) -> Self {
+        Flex {
+            grow: 0.,
+            shrink: 0.,
+            basis: Some(basis),
+        }
+    }
+}
+
+impl Default for Flex {
+    fn default() -> Self {
+        Flex {
+            grow: 0.,
+            shrink: 0.,
+            basis: None,
         }
     }
 }
 
+/// Boxes children of a [`Layout`] so children of different types can share a `Vec`.
+struct BoxedChild<'a>(Box<dyn MeasureDyn<'a> + 'a>);
+
+impl<'a> Measure for BoxedChild<'a> {
+    type Measured = BoxedMeasuredChild<'a>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let (size, widget) = self.0.measure(constraints, ui);
+        (size, BoxedMeasuredChild(widget))
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        self.0.min_content_width(ui)
+    }
+}
+
+struct BoxedMeasuredChild<'a>(Box<dyn DrawDyn + 'a>);
+
+impl<'a> Draw for BoxedMeasuredChild<'a> {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        self.0.draw(region, ui)
+    }
+
+    fn baseline(&self) -> Option<f32> {
+        self.0.baseline()
+    }
+}
+
 pub struct Layout<'a> {
     params: LayoutParams,
-    children: Vec<Box<dyn MeasureDyn<'a> + 'a>>,
+    children: Vec<ChildSlot<BoxedChild<'a>>>,
 }
 
 impl<'a> Layout<'a> {
@@ -132,172 +480,692 @@ impl<'a> Layout<'a> {
         }
     }
 
-    pub fn with_child(mut self, child: impl Measure + 'a) -> Self {
-        self.children.push(Box::new(child));
+    pub fn with_child(self, child: impl Measure + 'a) -> Self {
+        self.with_flex_child(child.flex(), child)
+    }
+
+    pub fn with_flex_child(mut self, flex: Flex, child: impl Measure + 'a) -> Self {
+        self.push_flex_child(flex, child);
+        self
+    }
+
+    /// Adds `child` only when `condition` holds.
+    pub fn with_child_if(self, condition: bool, child: impl Measure + 'a) -> Self {
+        if condition {
+            self.with_child(child)
+        } else {
+            self
+        }
+    }
+
+    pub fn with_children(mut self, children: impl IntoIterator<Item = impl Measure + 'a>) -> Self {
+        self.extend(children);
         self
     }
+
+    pub fn push_child(&mut self, child: impl Measure + 'a) {
+        self.push_flex_child(child.flex(), child);
+    }
+
+    pub fn push_flex_child(&mut self, flex: Flex, child: impl Measure + 'a) {
+        self.children
+            .push(ChildSlot::new(flex, BoxedChild(Box::new(child))));
+    }
+}
+
+impl<'a, W: Measure + 'a> Extend<W> for Layout<'a> {
+    fn extend<T: IntoIterator<Item = W>>(&mut self, iter: T) {
+        for child in iter {
+            self.push_child(child);
+        }
+    }
+}
+
+/// Collects the children into a layout with the default [`LayoutParams`].
+impl<'a, W: Measure + 'a> FromIterator<W> for Layout<'a> {
+    fn from_iter<T: IntoIterator<Item = W>>(iter: T) -> Self {
+        Layout::new(LayoutParams::default()).with_children(iter)
+    }
 }
 
 impl<'a> Measure for Layout<'a> {
     type Measured = MeasuredLayout<'a>;
 
-    fn measure(self, max_size: Vec2, ui: &Ui) -> (Vec2, Self::Measured) {
-        let child_size = match self.params.direction {
-            LayoutDirection::Row => vec2(max_size.x / self.children.len() as f32, max_size.y),
-            LayoutDirection::Column => vec2(max_size.x, max_size.y / self.children.len() as f32),
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let (size, inner) = measure_children(self.params, self.children, constraints, ui);
+
+        (size, MeasuredLayout { inner })
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        min_content_width(
+            &self.params,
+            self.children.iter().map(|it| it.min_content_width(ui)),
+            ui,
+        )
+    }
+}
+
+/// A layout over a fixed set of statically typed children, a tuple, array or `Vec` of widgets,
+/// which unlike [`Layout`] doesn't box each child. The flex factors of the children are taken from
+/// [`Measure::flex`].
+pub struct TypedLayout<C> {
+    params: LayoutParams,
+    children: C,
+}
+
+impl<C: LayoutChildren> TypedLayout<C> {
+    pub fn new(params: LayoutParams, children: C) -> Self {
+        Self { params, children }
+    }
+}
+
+impl<C: LayoutChildren> Measure for TypedLayout<C> {
+    type Measured = MeasuredTypedLayout<C::Slots>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        measure_children(self.params, self.children.into_slots(), constraints, ui)
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        min_content_width(
+            &self.params,
+            self.children.min_content_widths(ui).into_iter(),
+            ui,
+        )
+    }
+}
+
+/// Children which can be laid out by a [`TypedLayout`]: tuples, arrays and `Vec`s of widgets. This
+/// is sealed, so it can't be implemented outside of this crate.
+pub trait LayoutChildren: sealed::Sealed {
+    type Slots: ChildSlots;
+
+    fn into_slots(self) -> Self::Slots;
+
+    /// [`Measure::min_content_width`] of each child.
+    fn min_content_widths(&self, ui: &Ui) -> Vec<Option<f32>>;
+}
+
+/// Children of a layout held in place while they are measured and drawn, which the layout does
+/// one child at a time in whichever order it needs. This is sealed, so it can't be implemented
+/// outside of this crate.
+pub trait ChildSlots: sealed::Sealed {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn flex(&self, index: usize) -> Flex;
+
+    /// Measures the child at `index`, which must not have been measured before.
+    fn measure(&mut self, index: usize, constraints: Constraints, ui: &Ui) -> Vec2;
+
+    fn size(&self, index: usize) -> Vec2;
+
+    fn set_size(&mut self, index: usize, size: Vec2);
+
+    fn baseline(&self, index: usize) -> Option<f32>;
+
+    /// Draws the measured child at `index` into its own child `Ui` bounded by `rect`.
+    fn draw(&mut self, index: usize, rect: Rect, ui: &mut Ui) -> Response;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A child of a layout, along with its flex factors and its size once measured.
+pub struct ChildSlot<W: Measure> {
+    flex: Flex,
+    size: Vec2,
+    state: ChildState<W>,
+}
+
+enum ChildState<W: Measure> {
+    Unmeasured(W),
+    Measured(W::Measured),
+    Drawn,
+}
+
+impl<W: Measure> ChildSlot<W> {
+    fn new(flex: Flex, widget: W) -> Self {
+        Self {
+            flex,
+            size: Vec2::ZERO,
+            state: ChildState::Unmeasured(widget),
+        }
+    }
+
+    fn measure(&mut self, constraints: Constraints, ui: &Ui) -> Vec2 {
+        let ChildState::Unmeasured(widget) = std::mem::replace(&mut self.state, ChildState::Drawn)
+        else {
+            panic!("layout child measured twice");
         };
+        let (size, measured) = widget.measure(constraints, ui);
+
+        self.size = size;
+        self.state = ChildState::Measured(measured);
+        size
+    }
 
-        let measured_children: Vec<_> = self
-            .children
-            .into_iter()
-            .map(|it| it.measure(child_size, ui))
-            .collect();
-
-        let bounding_box = match self.params.direction {
-            LayoutDirection::Row => vec2(
-                measured_children.iter().map(|it| it.0.x).sum(),
-                max_partial(measured_children.iter().map(|it| it.0.y)).unwrap(),
-            ),
-            LayoutDirection::Column => vec2(
-                max_partial(measured_children.iter().map(|it| it.0.x)).unwrap(),
-                measured_children.iter().map(|it| it.0.y).sum(),
-            ),
+    fn baseline(&self) -> Option<f32> {
+        match &self.state {
+            ChildState::Measured(measured) => Draw::baseline(measured),
+            _ => None,
+        }
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        match &self.state {
+            ChildState::Unmeasured(widget) => widget.min_content_width(ui),
+            _ => None,
+        }
+    }
+
+    fn draw(&mut self, rect: Rect, ui: &mut Ui) -> Response {
+        let ChildState::Measured(measured) = std::mem::replace(&mut self.state, ChildState::Drawn)
+        else {
+            panic!("layout child drawn before being measured or drawn twice");
         };
 
-        (
-            bounding_box,
-            MeasuredLayout {
-                params: self.params,
-                children: measured_children,
-            },
+        measured.draw(rect, &mut child_ui(rect, ui))
+    }
+}
+
+macro_rules! impl_sequence_child_slots {
+    ($($ty:ty $(, const $n:ident)?);*) => {$(
+        impl<W: Measure $(, const $n: usize)?> sealed::Sealed for $ty {}
+
+        impl<W: Measure $(, const $n: usize)?> ChildSlots for $ty {
+            fn len(&self) -> usize {
+                <[ChildSlot<W>]>::len(self)
+            }
+
+            fn flex(&self, index: usize) -> Flex {
+                self[index].flex
+            }
+
+            fn measure(&mut self, index: usize, constraints: Constraints, ui: &Ui) -> Vec2 {
+                self[index].measure(constraints, ui)
+            }
+
+            fn size(&self, index: usize) -> Vec2 {
+                self[index].size
+            }
+
+            fn set_size(&mut self, index: usize, size: Vec2) {
+                self[index].size = size;
+            }
+
+            fn baseline(&self, index: usize) -> Option<f32> {
+                self[index].baseline()
+            }
+
+            fn draw(&mut self, index: usize, rect: Rect, ui: &mut Ui) -> Response {
+                self[index].draw(rect, ui)
+            }
+        }
+    )*};
+}
+
+impl_sequence_child_slots!(Vec<ChildSlot<W>>; [ChildSlot<W>; N], const N);
+
+impl<W: Measure> sealed::Sealed for Vec<W> {}
+
+impl<W: Measure> LayoutChildren for Vec<W> {
+    type Slots = Vec<ChildSlot<W>>;
+
+    fn into_slots(self) -> Self::Slots {
+        self.into_iter()
+            .map(|it| ChildSlot::new(it.flex(), it))
+            .collect()
+    }
+
+    fn min_content_widths(&self, ui: &Ui) -> Vec<Option<f32>> {
+        self.iter().map(|it| it.min_content_width(ui)).collect()
+    }
+}
+
+impl<W: Measure, const N: usize> sealed::Sealed for [W; N] {}
+
+impl<W: Measure, const N: usize> LayoutChildren for [W; N] {
+    type Slots = [ChildSlot<W>; N];
+
+    fn into_slots(self) -> Self::Slots {
+        self.map(|it| ChildSlot::new(it.flex(), it))
+    }
+
+    fn min_content_widths(&self, ui: &Ui) -> Vec<Option<f32>> {
+        self.iter().map(|it| it.min_content_width(ui)).collect()
+    }
+}
+
+macro_rules! impl_tuple_children {
+    ($len:literal; $($ty:ident $index:tt),+) => {
+        impl<$($ty: Measure),+> sealed::Sealed for ($($ty,)+) {}
+
+        impl<$($ty: Measure),+> LayoutChildren for ($($ty,)+) {
+            type Slots = ($(ChildSlot<$ty>,)+);
+
+            fn into_slots(self) -> Self::Slots {
+                ($(ChildSlot::new(self.$index.flex(), self.$index),)+)
+            }
+
+            fn min_content_widths(&self, ui: &Ui) -> Vec<Option<f32>> {
+                vec![$(self.$index.min_content_width(ui)),+]
+            }
+        }
+
+        impl<$($ty: Measure),+> sealed::Sealed for ($(ChildSlot<$ty>,)+) {}
+
+        impl<$($ty: Measure),+> ChildSlots for ($(ChildSlot<$ty>,)+) {
+            fn len(&self) -> usize {
+                $len
+            }
+
+            fn flex(&self, index: usize) -> Flex {
+                match index {
+                    $($index => self.$index.flex,)+
+                    _ => panic!("layout child {index} out of bounds"),
+                }
+            }
+
+            fn measure(&mut self, index: usize, constraints: Constraints, ui: &Ui) -> Vec2 {
+                match index {
+                    $($index => self.$index.measure(constraints, ui),)+
+                    _ => panic!("layout child {index} out of bounds"),
+                }
+            }
+
+            fn size(&self, index: usize) -> Vec2 {
+                match index {
+                    $($index => self.$index.size,)+
+                    _ => panic!("layout child {index} out of bounds"),
+                }
+            }
+
+            fn set_size(&mut self, index: usize, size: Vec2) {
+                match index {
+                    $($index => self.$index.size = size,)+
+                    _ => panic!("layout child {index} out of bounds"),
+                }
+            }
+
+            fn baseline(&self, index: usize) -> Option<f32> {
+                match index {
+                    $($index => self.$index.baseline(),)+
+                    _ => panic!("layout child {index} out of bounds"),
+                }
+            }
+
+            fn draw(&mut self, index: usize, rect: Rect, ui: &mut Ui) -> Response {
+                match index {
+                    $($index => self.$index.draw(rect, ui),)+
+                    _ => panic!("layout child {index} out of bounds"),
+                }
+            }
+        }
+    };
+}
+
+impl_tuple_children!(1; A 0);
+impl_tuple_children!(2; A 0, B 1);
+impl_tuple_children!(3; A 0, B 1, C 2);
+impl_tuple_children!(4; A 0, B 1, C 2, D 3);
+impl_tuple_children!(5; A 0, B 1, C 2, D 3, E 4);
+impl_tuple_children!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_tuple_children!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_tuple_children!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_tuple_children!(9; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_tuple_children!(10; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_tuple_children!(11; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_tuple_children!(12; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+fn measure_children<S: ChildSlots>(
+    params: LayoutParams,
+    mut children: S,
+    constraints: Constraints,
+    ui: &Ui,
+) -> (Vec2, MeasuredTypedLayout<S>) {
+    let direction = params.direction;
+    let main_axis_size = constraints.max.main_axis(direction);
+    let cross_axis_size = constraints.max.cross_axis(direction);
+    // Stretched children are measured at their own cross size and stretched to the layout's when
+    // drawn, so only a tight cross constraint has them fill the maximum.
+    let min_cross_axis_size = match params.cross_axis_alignment {
+        Alignment::Stretch => constraints.min.cross_axis(direction),
+        _ => 0.,
+    };
+    let child_constraints = |min_main_axis: f32, max_main_axis: f32| Constraints {
+        min: Vec2::ZERO
+            .with_main_axis(direction, min_main_axis)
+            .with_cross_axis(direction, min_cross_axis_size),
+        max: Vec2::ZERO
+            .with_main_axis(direction, max_main_axis)
+            .with_cross_axis(direction, cross_axis_size),
+    };
+    let gap = params
+        .gap
+        .unwrap_or_else(|| ui.style().spacing.item_spacing.main_axis(direction));
+    let total_gap = gap * children.len().saturating_sub(1) as f32;
+    let text_direction = params
+        .text_direction
+        .unwrap_or_else(|| TextDirection::from_ui(ui));
+    // Children pick up their direction from the `Ui` they're measured in, so when it differs from
+    // the inherited one they're measured in an invisible `Ui` recording it.
+    let direction_ui =
+        (text_direction != TextDirection::from_ui(ui)).then(|| text_direction.measure_ui(ui));
+    let ui = direction_ui.as_ref().unwrap_or(ui);
+    let is_flexible =
+        |flex: Flex| flex.basis.is_some() || (flex.grow > 0. && main_axis_size.is_finite());
+
+    // Children without flex factors are measured first at their natural size, the remaining
+    // space is then divided between the flexible ones.
+    let mut consumed_space = 0.;
+    for index in 0..children.len() {
+        if !is_flexible(children.flex(index)) {
+            let size = children.measure(index, child_constraints(0., f32::INFINITY), ui);
+            consumed_space += size.main_axis(direction);
+        }
+    }
+
+    let free_space = FreeSpace::new(
+        (main_axis_size - consumed_space - total_gap).max(0.),
+        (0..children.len())
+            .map(|index| children.flex(index))
+            .filter(|&flex| is_flexible(flex)),
+    );
+
+    for index in 0..children.len() {
+        if !is_flexible(children.flex(index)) {
+            continue;
+        }
+
+        let main_size = free_space.allot(children.flex(index));
+        let size = children.measure(index, child_constraints(main_size, main_size), ui);
+
+        children.set_size(
+            index,
+            size.with_main_axis(direction, main_size.max(size.main_axis(direction))),
+        );
+    }
+
+    let baseline = match params.cross_axis_alignment {
+        Alignment::Baseline if direction.is_horizontal() => {
+            max_partial((0..children.len()).filter_map(|index| children.baseline(index)))
+        }
+        _ => None,
+    };
+
+    let bounding_box = Vec2::ZERO
+        .with_main_axis(
+            direction,
+            (0..children.len())
+                .map(|index| children.size(index).main_axis(direction))
+                .sum::<f32>()
+                + total_gap,
         )
+        .with_cross_axis(
+            direction,
+            max_partial((0..children.len()).map(|index| {
+                baseline_offset(baseline, children.baseline(index))
+                    + direction.cross_axis(children.size(index))
+            }))
+            .unwrap_or(0.),
+        );
+
+    (
+        constraints.constrain(bounding_box),
+        MeasuredTypedLayout {
+            params,
+            content_size: bounding_box,
+            gap,
+            text_direction,
+            baseline,
+            children,
+        },
+    )
+}
+
+/// Min content width of a layout's children, which is their sum and the gaps between them in a
+/// row, and that of the widest child in a column. Children which can't tell count as zero.
+fn min_content_width(
+    params: &LayoutParams,
+    widths: impl ExactSizeIterator<Item = Option<f32>>,
+    ui: &Ui,
+) -> Option<f32> {
+    let direction = params.direction;
+    let children = widths.len();
+    let widths = widths.map(|it| it.unwrap_or(0.));
+
+    if direction.is_horizontal() {
+        let gap = params
+            .gap
+            .unwrap_or_else(|| ui.style().spacing.item_spacing.main_axis(direction));
+
+        Some(widths.sum::<f32>() + gap * children.saturating_sub(1) as f32)
+    } else {
+        Some(max_partial(widths).unwrap_or(0.))
+    }
+}
+
+/// Space left over for the flexible children, which grows or shrinks each basis so that together
+/// they fill the available space, weighted by the children's flex factors. Shrinking is
+/// additionally weighted by the basis, so larger children give up more space.
+struct FreeSpace {
+    free_space: f32,
+    total_grow: f32,
+    total_shrink: f32,
+}
+
+impl FreeSpace {
+    fn new(available: f32, flexes: impl Iterator<Item = Flex> + Clone) -> Self {
+        Self {
+            free_space: available - flexes.clone().map(|it| it.basis.unwrap_or(0.)).sum::<f32>(),
+            total_grow: flexes.clone().map(|it| it.grow).sum(),
+            total_shrink: flexes.map(|it| it.basis.unwrap_or(0.) * it.shrink).sum(),
+        }
+    }
+
+    fn allot(&self, flex: Flex) -> f32 {
+        let basis = flex.basis.unwrap_or(0.);
+
+        if self.free_space > 0. && self.free_space.is_finite() && self.total_grow > 0. {
+            basis + self.free_space * flex.grow / self.total_grow
+        } else if self.free_space < 0. && self.total_shrink > 0. {
+            (basis + self.free_space * basis * flex.shrink / self.total_shrink).max(0.)
+        } else {
+            basis
+        }
+    }
+}
+
+/// Cross axis offset which lines the child's baseline up with `baseline`.
+pub(crate) fn baseline_offset(baseline: Option<f32>, child_baseline: Option<f32>) -> f32 {
+    match (baseline, child_baseline) {
+        (Some(baseline), Some(child_baseline)) => baseline - child_baseline,
+        _ => 0.,
     }
 }
 
 pub struct MeasuredLayout<'a> {
-    params: LayoutParams,
-    children: Vec<(Vec2, Box<dyn DrawDyn + 'a>)>,
+    inner: MeasuredTypedLayout<Vec<ChildSlot<BoxedChild<'a>>>>,
 }
 
 impl<'a> Draw for MeasuredLayout<'a> {
-    fn draw(mut self, available_space: Rect, ui: &mut Ui) {
-        let calculate_cross_axis =
-            |cross_axis_size: f32, element_size: f32| match self.params.cross_axis_alignment {
-                Alignment::Start => 0.,
-                Alignment::End => cross_axis_size - element_size,
-                Alignment::Center => (cross_axis_size - element_size) / 2.0,
-            };
+    fn baseline(&self) -> Option<f32> {
+        Draw::baseline(&self.inner)
+    }
 
-        let rects: Vec<_> = match self.params.main_axis_alignment {
-            Alignment::Start => self
-                .children
-                .into_iter()
-                .scan(0_f32, |current_main_axis, (measurement, child)| {
-                    let rect = Rect::from_min_size(
-                        available_space.min
-                            + Vec2::ZERO
-                                .with_main_axis(self.params.direction, *current_main_axis)
-                                .with_cross_axis(
-                                    self.params.direction,
-                                    calculate_cross_axis(
-                                        available_space.size().cross_axis(self.params.direction),
-                                        measurement.cross_axis(self.params.direction),
-                                    ),
-                                ),
-                        measurement,
-                    );
-
-                    *current_main_axis += measurement.main_axis(self.params.direction);
-
-                    Some((rect, child))
-                })
-                .collect(),
-            Alignment::End => {
-                let consumed_space: f32 = self
-                    .children
-                    .iter()
-                    .map(|it| self.params.direction.main_axis(it.0))
-                    .sum();
-                let spacing_width =
-                    self.params.direction.main_axis(available_space.size()) - consumed_space;
-
-                self.children
-                    .into_iter()
-                    .scan(0_f32, |current_main_axis, (measurement, child)| {
-                        let rect = Rect::from_min_size(
-                            available_space.min
-                                + Vec2::ZERO
-                                    .with_main_axis(
-                                        self.params.direction,
-                                        *current_main_axis + spacing_width,
-                                    )
-                                    .with_cross_axis(
-                                        self.params.direction,
-                                        calculate_cross_axis(
-                                            available_space
-                                                .size()
-                                                .cross_axis(self.params.direction),
-                                            measurement.cross_axis(self.params.direction),
-                                        ),
-                                    ),
-                            measurement,
-                        );
-
-                        *current_main_axis += measurement.main_axis(self.params.direction);
-
-                        Some((rect, child))
-                    })
-                    .collect()
+    fn draw(self, available_space: Rect, ui: &mut Ui) -> Response {
+        self.inner.draw(available_space, ui)
+    }
+}
+
+impl<'a> MeasuredLayout<'a> {
+    /// Size the children take up, before it was clamped to the constraints the layout was
+    /// measured with.
+    pub fn content_size(&self) -> Vec2 {
+        self.inner.content_size()
+    }
+
+    /// Whether the children don't fit into a region of `size`.
+    pub fn overflows(&self, size: Vec2) -> bool {
+        self.inner.overflows(size)
+    }
+}
+
+pub struct MeasuredTypedLayout<S> {
+    params: LayoutParams,
+    content_size: Vec2,
+    gap: f32,
+    text_direction: TextDirection,
+    /// Shared baseline of the children when they are baseline aligned.
+    baseline: Option<f32>,
+    children: S,
+}
+
+impl<S: ChildSlots> Draw for MeasuredTypedLayout<S> {
+    fn baseline(&self) -> Option<f32> {
+        match (self.params.direction, &self.params.main_axis_alignment) {
+            (direction, _) if direction.is_horizontal() => self.baseline,
+            (LayoutDirection::Column, MainAxisAlignment::Start) if !self.children.is_empty() => {
+                self.children.baseline(0)
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(mut self, available_space: Rect, ui: &mut Ui) -> Response {
+        let direction = self.params.direction;
+        let mut ui = ui.new_child(self.text_direction.ui_builder().max_rect(available_space));
+        let response = ui.interact(available_space, ui.auto_id_with("layout"), Sense::hover());
+
+        match self.params.overflow {
+            Overflow::Visible => self.draw_children(available_space, &mut ui, response),
+            Overflow::Clip => {
+                ui.set_clip_rect(available_space.intersect(ui.clip_rect()));
+
+                self.draw_children(available_space, &mut ui, response)
             }
-            Alignment::Center => {
-                let consumed_space: f32 = self
-                    .children
-                    .iter()
-                    .map(|it| self.params.direction.main_axis(it.0))
-                    .sum();
-                let spacing_size =
-                    self.params.direction.main_axis(available_space.size()) - consumed_space;
-                let starting_offset = (spacing_size / 2.0);
-
-                self.children
-                    .into_iter()
-                    .scan(0_f32, |current_main_axis, (measurement, child)| {
-                        let rect = Rect::from_min_size(
-                            available_space.min
-                                + Vec2::ZERO
-                                    .with_main_axis(
-                                        self.params.direction,
-                                        *current_main_axis + starting_offset,
-                                    )
-                                    .with_cross_axis(
-                                        self.params.direction,
-                                        calculate_cross_axis(
-                                            available_space
-                                                .size()
-                                                .cross_axis(self.params.direction),
-                                            measurement.cross_axis(self.params.direction),
-                                        ),
-                                    ),
-                            measurement,
-                        );
-
-                        *current_main_axis += measurement.main_axis(self.params.direction);
-
-                        Some((rect, child))
+            Overflow::Scroll => {
+                let size = available_space.size().with_main_axis(
+                    direction,
+                    self.content_size
+                        .main_axis(direction)
+                        .max(available_space.size().main_axis(direction)),
+                );
+
+                // `ui` has the same id as the children of sibling layouts, so salt the scroll area
+                // with an id unique among them.
+                let id_salt = self
+                    .params
+                    .id_salt
+                    .unwrap_or_else(|| ui.auto_id_with("scroll"));
+                egui::ScrollArea::new([direction.is_horizontal(), !direction.is_horizontal()])
+                    .id_salt(id_salt)
+                    .auto_shrink([false; 2])
+                    .show(&mut ui, |ui| {
+                        let (region, _) = ui.allocate_exact_size(size, Sense::hover());
+
+                        self.draw_children(region, ui, response)
                     })
-                    .collect()
+                    .inner
             }
-        };
+        }
+    }
+}
+
+impl<S> MeasuredTypedLayout<S> {
+    /// Size the children take up, before it was clamped to the constraints the layout was
+    /// measured with.
+    pub fn content_size(&self) -> Vec2 {
+        self.content_size
+    }
+
+    /// Whether the children don't fit into a region of `size`.
+    pub fn overflows(&self, size: Vec2) -> bool {
+        self.content_size.x > size.x || self.content_size.y > size.y
+    }
+}
+
+impl<S: ChildSlots> MeasuredTypedLayout<S> {
+    /// Places the children within `region` and draws them, folding their responses into
+    /// `response`.
+    fn draw_children(&mut self, region: Rect, ui: &mut Ui, mut response: Response) -> Response {
+        let direction = self.params.direction;
+        let children = self.children.len();
+        let consumed_space: f32 = (0..children)
+            .map(|index| direction.main_axis(self.children.size(index)))
+            .sum::<f32>()
+            + self.gap * children.saturating_sub(1) as f32;
+        let spacing_size = region.size().main_axis(direction) - consumed_space;
+        let (starting_offset, spacing_between) = self
+            .params
+            .main_axis_alignment
+            .distribute(spacing_size, children);
+
+        let mut current_main_axis = starting_offset;
 
-        for (rect, child) in rects {
-            let mut ui = ui.child_ui(rect, egui::Layout::default(), None);
+        for index in 0..children {
+            let child_size = self.children.size(index);
+            let size = match self.params.cross_axis_alignment {
+                Alignment::Stretch => {
+                    child_size.with_cross_axis(direction, region.size().cross_axis(direction))
+                }
+                _ => child_size,
+            };
+            let cross_axis_size = region.size().cross_axis(direction);
+            let cross_axis_offset = match self.params.cross_axis_alignment {
+                Alignment::Start | Alignment::Stretch => 0.,
+                Alignment::End => cross_axis_size - size.cross_axis(direction),
+                Alignment::Center => (cross_axis_size - size.cross_axis(direction)) / 2.0,
+                Alignment::Baseline => {
+                    baseline_offset(self.baseline, self.children.baseline(index))
+                }
+            };
+            let main_axis = direction.place_main_axis(
+                self.text_direction,
+                region.size().main_axis(direction),
+                current_main_axis,
+                size.main_axis(direction),
+            );
+            let cross_axis = direction.place_cross_axis(
+                self.text_direction,
+                cross_axis_size,
+                cross_axis_offset,
+                size.cross_axis(direction),
+            );
+            let rect = Rect::from_min_size(
+                region.min
+                    + Vec2::ZERO
+                        .with_main_axis(direction, main_axis)
+                        .with_cross_axis(direction, cross_axis),
+                size,
+            );
 
-            child.draw(rect, &mut ui);
+            response |= self.children.draw(index, rect, ui);
+            current_main_axis += child_size.main_axis(direction) + self.gap + spacing_between;
         }
+
+        response
     }
 }
 
-fn max_partial<T: PartialOrd>(values: impl Iterator<Item = T>) -> Option<T> {
+/// Creates a child `Ui` bounded by `rect`, which inherits the text direction of `ui`.
+fn child_ui(rect: Rect, ui: &mut Ui) -> Ui {
+    ui.new_child(TextDirection::from_ui(ui).ui_builder().max_rect(rect))
+}
+
+/// Draws a boxed child into its own child `Ui` bounded by `rect`, which inherits the text
+/// direction of `ui`.
+pub(crate) fn draw_child(child: Box<dyn DrawDyn + '_>, rect: Rect, ui: &mut Ui) -> Response {
+    child.draw(rect, &mut child_ui(rect, ui))
+}
+
+pub(crate) fn max_partial<T: PartialOrd>(values: impl Iterator<Item = T>) -> Option<T> {
     values.fold(None, |max, x| match max {
         Some(m) if m.partial_cmp(&x) == Some(std::cmp::Ordering::Greater) => Some(m),
         _ => Some(x),
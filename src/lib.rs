@@ -1,2 +1,5 @@
+mod macros;
+
+pub mod ext;
 pub mod layout;
 pub mod widgets;
@@ -0,0 +1,126 @@
+/// Builds a [`Layout`](crate::layout::Layout) from its params and a comma separated list of
+/// children, expanding to the usual `with_child`/`with_flex_child` calls.
+///
+/// Each child is one of:
+///
+/// - `child`
+/// - `flex => child`, adding `child` with the given [`Flex`](crate::layout::Flex)
+/// - `if condition => child` or `if condition => flex => child`, adding the child only when
+///   `condition` holds
+/// - `for pattern in iter => child` or `for pattern in iter => flex => child`, adding a child per
+///   item
+///
+/// A child which is itself an `if` expression needs to be wrapped in parentheses.
+///
+/// ```
+/// use egui_layout::layout::{Flex, LayoutDirection, LayoutParams};
+/// use egui_layout::layout;
+/// use egui_layout::widgets::text::Text;
+///
+/// let show_footer = true;
+/// let items = ["a", "b"];
+///
+/// let layout = layout!(
+///     LayoutParams {
+///         direction: LayoutDirection::Column,
+///         ..Default::default()
+///     };
+///     Text::new("header"),
+///     for item in items => Text::new(item),
+///     Flex::grow(1.) => Text::new("body"),
+///     if show_footer => Text::new("footer"),
+/// );
+/// ```
+#[macro_export]
+macro_rules! layout {
+    (@children $layout:ident;) => {};
+    (@children $layout:ident; if $condition:expr => $flex:expr => $child:expr $(, $($rest:tt)*)?) => {
+        if $condition {
+            $layout = $layout.with_flex_child($flex, $child);
+        }
+        $crate::layout!(@children $layout; $($($rest)*)?);
+    };
+    (@children $layout:ident; if $condition:expr => $child:expr $(, $($rest:tt)*)?) => {
+        if $condition {
+            $layout = $layout.with_child($child);
+        }
+        $crate::layout!(@children $layout; $($($rest)*)?);
+    };
+    (@children $layout:ident; for $pattern:pat in $iter:expr => $flex:expr => $child:expr $(, $($rest:tt)*)?) => {
+        for $pattern in $iter {
+            $layout = $layout.with_flex_child($flex, $child);
+        }
+        $crate::layout!(@children $layout; $($($rest)*)?);
+    };
+    (@children $layout:ident; for $pattern:pat in $iter:expr => $child:expr $(, $($rest:tt)*)?) => {
+        for $pattern in $iter {
+            $layout = $layout.with_child($child);
+        }
+        $crate::layout!(@children $layout; $($($rest)*)?);
+    };
+    (@children $layout:ident; $flex:expr => $child:expr $(, $($rest:tt)*)?) => {
+        $layout = $layout.with_flex_child($flex, $child);
+        $crate::layout!(@children $layout; $($($rest)*)?);
+    };
+    (@children $layout:ident; $child:expr $(, $($rest:tt)*)?) => {
+        $layout = $layout.with_child($child);
+        $crate::layout!(@children $layout; $($($rest)*)?);
+    };
+    ($params:expr; $($children:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut layout = $crate::layout::Layout::new($params);
+        $crate::layout!(@children layout; $($children)*);
+        layout
+    }};
+}
+
+/// Builds a [`LayoutDirection::Row`](crate::layout::LayoutDirection::Row) layout, taking the same
+/// children as [`layout!`]. Other [`LayoutParams`](crate::layout::LayoutParams) fields may be
+/// given before a `;`, as in `row![gap: Some(4.); a, b]`.
+#[macro_export]
+macro_rules! row {
+    ($($field:ident: $value:expr),+ $(,)?; $($children:tt)*) => {
+        $crate::layout!(
+            $crate::layout::LayoutParams {
+                direction: $crate::layout::LayoutDirection::Row,
+                $($field: $value,)+
+                ..::core::default::Default::default()
+            };
+            $($children)*
+        )
+    };
+    ($($children:tt)*) => {
+        $crate::layout!(
+            $crate::layout::LayoutParams {
+                direction: $crate::layout::LayoutDirection::Row,
+                ..::core::default::Default::default()
+            };
+            $($children)*
+        )
+    };
+}
+
+/// Builds a [`LayoutDirection::Column`](crate::layout::LayoutDirection::Column) layout, taking
+/// the same arguments as [`row!`].
+#[macro_export]
+macro_rules! column {
+    ($($field:ident: $value:expr),+ $(,)?; $($children:tt)*) => {
+        $crate::layout!(
+            $crate::layout::LayoutParams {
+                direction: $crate::layout::LayoutDirection::Column,
+                $($field: $value,)+
+                ..::core::default::Default::default()
+            };
+            $($children)*
+        )
+    };
+    ($($children:tt)*) => {
+        $crate::layout!(
+            $crate::layout::LayoutParams {
+                direction: $crate::layout::LayoutDirection::Column,
+                ..::core::default::Default::default()
+            };
+            $($children)*
+        )
+    };
+}
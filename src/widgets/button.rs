@@ -0,0 +1,43 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Galley, Rect, Response, TextStyle, Ui, Vec2, WidgetText};
+use std::sync::Arc;
+
+/// An [`egui::Button`] sized from the style's button padding and the laid out text.
+pub struct Button {
+    text: WidgetText,
+}
+
+impl Button {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+impl Measure for Button {
+    type Measured = MeasuredButton;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let button_padding = ui.spacing().button_padding;
+        let galley = self.text.into_galley(
+            ui,
+            None,
+            constraints.max.x - 2.0 * button_padding.x,
+            TextStyle::Button,
+        );
+
+        let mut size = galley.size() + 2.0 * button_padding;
+        size.y = size.y.max(ui.spacing().interact_size.y);
+
+        (constraints.constrain(size), MeasuredButton { galley })
+    }
+}
+
+pub struct MeasuredButton {
+    galley: Arc<Galley>,
+}
+
+impl Draw for MeasuredButton {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        ui.put(region, egui::Button::new(self.galley))
+    }
+}
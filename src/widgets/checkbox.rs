@@ -0,0 +1,76 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Galley, Rect, Response, TextStyle, Ui, Vec2, WidgetText, vec2};
+use std::sync::Arc;
+
+/// Lays out the label of a checkbox or radio button, which follows the icon, and returns it with
+/// the size of the whole widget.
+pub(crate) fn measure_icon_label(
+    text: WidgetText,
+    constraints: Constraints,
+    ui: &Ui,
+) -> (Vec2, Option<Arc<Galley>>) {
+    let spacing = ui.spacing();
+    let icon_width = spacing.icon_width;
+
+    let (mut size, galley) = if text.is_empty() {
+        (vec2(icon_width, 0.0), None)
+    } else {
+        let icon_size = vec2(icon_width + spacing.icon_spacing, 0.0);
+        let galley = text.into_galley(ui, None, constraints.max.x - icon_size.x, TextStyle::Button);
+
+        (
+            (icon_size + galley.size()).max(spacing.interact_size),
+            Some(galley),
+        )
+    };
+
+    size = size.max(Vec2::splat(spacing.interact_size.y));
+    size.y = size.y.max(icon_width);
+
+    (constraints.constrain(size), galley)
+}
+
+/// An [`egui::Checkbox`] sized from the style's icon spacing and the laid out label.
+pub struct Checkbox<'a> {
+    checked: &'a mut bool,
+    text: WidgetText,
+}
+
+impl<'a> Checkbox<'a> {
+    pub fn new(checked: &'a mut bool, text: impl Into<WidgetText>) -> Self {
+        Self {
+            checked,
+            text: text.into(),
+        }
+    }
+}
+
+impl<'a> Measure for Checkbox<'a> {
+    type Measured = MeasuredCheckbox<'a>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let (size, galley) = measure_icon_label(self.text, constraints, ui);
+
+        (
+            size,
+            MeasuredCheckbox {
+                checked: self.checked,
+                galley,
+            },
+        )
+    }
+}
+
+pub struct MeasuredCheckbox<'a> {
+    checked: &'a mut bool,
+    galley: Option<Arc<Galley>>,
+}
+
+impl<'a> Draw for MeasuredCheckbox<'a> {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        match self.galley {
+            Some(galley) => ui.put(region, egui::Checkbox::new(self.checked, galley)),
+            None => ui.put(region, egui::Checkbox::without_text(self.checked)),
+        }
+    }
+}
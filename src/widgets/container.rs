@@ -0,0 +1,55 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Rect, Response, Ui, Vec2};
+
+/// Paints the fill, stroke, corner radius and shadow of an [`egui::Frame`] around its child. The
+/// frame's inner and outer margins are part of the measured size.
+pub struct Container<Child> {
+    style: egui::Frame,
+    child: Child,
+}
+
+impl<Child: Measure> Container<Child> {
+    pub fn new(style: egui::Frame, child: Child) -> Self {
+        Self { style, child }
+    }
+}
+
+impl<Child: Measure> Measure for Container<Child> {
+    type Measured = MeasuredContainer<Child::Measured>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let margin = self.style.total_margin().sum();
+        let (child_size, child) = self.child.measure(constraints.deflate(margin), ui);
+
+        (
+            constraints.constrain(child_size + margin),
+            MeasuredContainer {
+                style: self.style,
+                child,
+            },
+        )
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        Some(self.child.min_content_width(ui)? + self.style.total_margin().sum().x)
+    }
+}
+
+pub struct MeasuredContainer<Child> {
+    style: egui::Frame,
+    child: Child,
+}
+
+impl<Child: Draw> Draw for MeasuredContainer<Child> {
+    fn baseline(&self) -> Option<f32> {
+        Some(self.child.baseline()? + self.style.total_margin().top)
+    }
+
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        let content_rect = region - self.style.total_margin();
+
+        ui.painter().add(self.style.paint(content_rect));
+
+        self.child.draw(content_rect, ui)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Rect, Response, Ui, UiBuilder, Vec2};
+
+/// Measures arbitrary egui content by laying it out in an invisible sizing pass `Ui` during
+/// `measure`, so it has the correct size on the first frame without discarding it.
+///
+/// `add_contents` runs twice per frame, once for measuring and once for drawing, so it should not
+/// have side effects beyond adding widgets.
+pub struct EagerMeasuredWidget<F> {
+    add_contents: F,
+    id: egui::Id,
+}
+
+impl<F: FnMut(&mut Ui) -> Response> EagerMeasuredWidget<F> {
+    pub fn new(add_contents: F, id: egui::Id) -> Self {
+        Self { add_contents, id }
+    }
+}
+
+impl<F: FnMut(&mut Ui) -> Response> Measure for EagerMeasuredWidget<F> {
+    type Measured = EagerMeasuredWidgetMeasured<F>;
+
+    fn measure(mut self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let mut sizing_ui = Ui::new(
+            ui.ctx().clone(),
+            self.id.with("sizing_pass"),
+            UiBuilder::new()
+                .layer_id(ui.layer_id())
+                .max_rect(Rect::from_min_size(ui.max_rect().min, constraints.max))
+                .layout(*ui.layout())
+                .sizing_pass()
+                .invisible(),
+        );
+        (self.add_contents)(&mut sizing_ui);
+
+        (
+            constraints.constrain(sizing_ui.min_rect().size()),
+            EagerMeasuredWidgetMeasured {
+                add_contents: self.add_contents,
+            },
+        )
+    }
+}
+
+pub struct EagerMeasuredWidgetMeasured<F> {
+    add_contents: F,
+}
+
+impl<F: FnMut(&mut Ui) -> Response> Draw for EagerMeasuredWidgetMeasured<F> {
+    fn draw(mut self, _region: Rect, ui: &mut Ui) -> Response {
+        (self.add_contents)(ui)
+    }
+}
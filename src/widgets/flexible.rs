@@ -0,0 +1,31 @@
+use crate::layout::{Constraints, Flex, Measure};
+use egui::{Ui, Vec2};
+
+/// Gives `child` flex factors when it's added to a layout, for layouts whose children can't be
+/// given them directly such as a [`TypedLayout`](crate::layout::TypedLayout).
+pub struct Flexible<W> {
+    flex: Flex,
+    child: W,
+}
+
+impl<W: Measure> Flexible<W> {
+    pub fn new(flex: Flex, child: W) -> Self {
+        Self { flex, child }
+    }
+}
+
+impl<W: Measure> Measure for Flexible<W> {
+    type Measured = W::Measured;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        self.child.measure(constraints, ui)
+    }
+
+    fn flex(&self) -> Flex {
+        self.flex
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        self.child.min_content_width(ui)
+    }
+}
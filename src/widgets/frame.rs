@@ -1,5 +1,5 @@
-use crate::layout::{Draw, Measure};
-use egui::{Rect, Ui, Vec2};
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Rect, Response, Ui, Vec2};
 
 pub struct Frame<Child> {
     child: Child,
@@ -14,10 +14,30 @@ impl<Child: Measure> Frame<Child> {
 impl<Child: Measure> Measure for Frame<Child> {
     type Measured = MeasuredFrame<Child::Measured>;
 
-    fn measure(self, max_size: Vec2, ui: &Ui) -> (Vec2, Self::Measured) {
-        let (_ignored_child_size, child) = self.child.measure(max_size, ui);
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let max_size = constraints.max;
+        let (child_size, child) = self.child.measure(constraints, ui);
 
-        (max_size, MeasuredFrame { child })
+        // Expand to fill the available space, falling back to the child's size along unbounded
+        // axes.
+        let size = Vec2::new(
+            if max_size.x.is_finite() {
+                max_size.x
+            } else {
+                child_size.x
+            },
+            if max_size.y.is_finite() {
+                max_size.y
+            } else {
+                child_size.y
+            },
+        );
+
+        (size, MeasuredFrame { child })
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        self.child.min_content_width(ui)
     }
 }
 
@@ -26,7 +46,11 @@ pub struct MeasuredFrame<Child> {
 }
 
 impl<Child: Draw> Draw for MeasuredFrame<Child> {
-    fn draw(self, region: Rect, ui: &mut Ui) {
-        self.child.draw(region, ui);
+    fn baseline(&self) -> Option<f32> {
+        self.child.baseline()
+    }
+
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        self.child.draw(region, ui)
     }
 }
@@ -0,0 +1,368 @@
+use crate::layout::{Constraints, Draw, DrawDyn, Measure, MeasureDyn, draw_child, max_partial};
+use egui::{Rect, Response, Sense, Ui, Vec2, vec2};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Track {
+    /// A fixed size in points.
+    Fixed(f32),
+    /// A share of the space left over by the other tracks, weighted against the other fractions.
+    /// Sized like [`Track::MaxContent`] when the grid is unbounded along the track's axis.
+    Fraction(f32),
+    /// Sized to the content, wrapping it to an even share of the width left over by the other
+    /// tracks.
+    Auto,
+    /// Sized to the narrowest the content can be, e.g. the longest word of a text, as reported by
+    /// [`Measure::min_content_width`]. Content which can't tell is measured at a width of zero.
+    MinContent,
+    /// Sized to the content without any wrapping.
+    MaxContent,
+}
+
+#[derive(Default)]
+pub struct GridParams {
+    pub columns: Vec<Track>,
+    /// Rows beyond the ones listed here, which are needed to hold the cells, are [`Track::Auto`].
+    pub rows: Vec<Track>,
+    /// Defaults to the style's horizontal `item_spacing`.
+    pub column_gap: Option<f32>,
+    /// Defaults to the style's vertical `item_spacing`.
+    pub row_gap: Option<f32>,
+}
+
+#[derive(Clone, Copy)]
+struct CellPlacement {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
+
+impl CellPlacement {
+    fn columns(&self) -> std::ops::Range<usize> {
+        self.column..self.column + self.column_span
+    }
+
+    fn rows(&self) -> std::ops::Range<usize> {
+        self.row..self.row + self.row_span
+    }
+}
+
+struct GridCell<'a> {
+    placement: CellPlacement,
+    widget: Box<dyn MeasureDyn<'a> + 'a>,
+}
+
+/// Places children into cells of shared column and row tracks, so they line up across rows.
+pub struct Grid<'a> {
+    params: GridParams,
+    cells: Vec<GridCell<'a>>,
+}
+
+impl<'a> Grid<'a> {
+    pub fn new(params: GridParams) -> Self {
+        Self {
+            params,
+            cells: vec![],
+        }
+    }
+
+    pub fn with_cell(self, column: usize, row: usize, child: impl Measure + 'a) -> Self {
+        self.with_spanning_cell(column, row, 1, 1, child)
+    }
+
+    pub fn with_spanning_cell(
+        mut self,
+        column: usize,
+        row: usize,
+        column_span: usize,
+        row_span: usize,
+        child: impl Measure + 'a,
+    ) -> Self {
+        self.cells.push(GridCell {
+            placement: CellPlacement {
+                column,
+                row,
+                column_span: column_span.max(1),
+                row_span: row_span.max(1),
+            },
+            widget: Box::new(child),
+        });
+        self
+    }
+}
+
+fn resolve_tracks(tracks: &[Track], count: usize) -> Vec<Track> {
+    (0..count)
+        .map(|index| tracks.get(index).copied().unwrap_or(Track::Auto))
+        .collect()
+}
+
+/// Size of a run of tracks, including the gaps between them.
+fn span_size(sizes: &[f32], span: std::ops::Range<usize>, gap: f32) -> f32 {
+    let count = span.len();
+    sizes[span].iter().sum::<f32>() + gap * count.saturating_sub(1) as f32
+}
+
+/// Distance from the start of the grid to the start of the track at `index`.
+fn track_offset(sizes: &[f32], index: usize, gap: f32) -> f32 {
+    sizes[..index].iter().sum::<f32>() + gap * index as f32
+}
+
+/// Evenly grows the tracks within `span` for which `grows` holds, until the span is at least
+/// `size`.
+fn grow_span(
+    sizes: &mut [f32],
+    span: std::ops::Range<usize>,
+    grows: impl Fn(usize) -> bool,
+    size: f32,
+    gap: f32,
+) {
+    let missing = size - span_size(sizes, span.clone(), gap);
+    let growing = span.clone().filter(|it| grows(*it)).count();
+    if missing <= 0. || !missing.is_finite() || growing == 0 {
+        return;
+    }
+
+    for index in span.filter(|it| grows(*it)) {
+        sizes[index] += missing / growing as f32;
+    }
+}
+
+/// Splits the space left over by the other tracks between the fraction tracks.
+fn distribute_fractions(tracks: &[Track], sizes: &mut [f32], available: f32, gap: f32) {
+    let total_fraction: f32 = tracks
+        .iter()
+        .map(|it| match it {
+            Track::Fraction(fraction) => *fraction,
+            _ => 0.,
+        })
+        .sum();
+    if total_fraction <= 0. || !available.is_finite() {
+        return;
+    }
+
+    let leftover = (available - span_size(sizes, 0..sizes.len(), gap)).max(0.);
+    for (track, size) in tracks.iter().zip(sizes.iter_mut()) {
+        if let Track::Fraction(fraction) = track {
+            *size = size.max(leftover * fraction / total_fraction);
+        }
+    }
+}
+
+impl<'a> Measure for Grid<'a> {
+    type Measured = MeasuredGrid<'a>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let item_spacing = ui.style().spacing.item_spacing;
+        let column_gap = self.params.column_gap.unwrap_or(item_spacing.x);
+        let row_gap = self.params.row_gap.unwrap_or(item_spacing.y);
+
+        let column_count = self
+            .cells
+            .iter()
+            .map(|it| it.placement.columns().end)
+            .fold(self.params.columns.len(), usize::max);
+        let row_count = self
+            .cells
+            .iter()
+            .map(|it| it.placement.rows().end)
+            .fold(self.params.rows.len(), usize::max);
+        let columns = resolve_tracks(&self.params.columns, column_count);
+        let rows = resolve_tracks(&self.params.rows, row_count);
+
+        let fixed_size = |track: &Track| match track {
+            Track::Fixed(size) => *size,
+            _ => 0.,
+        };
+        let mut column_widths: Vec<f32> = columns.iter().map(fixed_size).collect();
+        let fixed_row_heights: Vec<f32> = rows.iter().map(fixed_size).collect();
+
+        let width_is_bounded = constraints.max.x.is_finite();
+        let is_content_column = |track: &Track| match track {
+            Track::Fixed(_) => false,
+            Track::Fraction(_) => !width_is_bounded,
+            _ => true,
+        };
+        let auto_columns = columns.iter().filter(|it| **it == Track::Auto).count();
+        let max_cell_height = |placement: &CellPlacement| {
+            if rows[placement.rows()]
+                .iter()
+                .all(|it| matches!(it, Track::Fixed(_)))
+            {
+                span_size(&fixed_row_heights, placement.rows(), row_gap)
+            } else {
+                f32::INFINITY
+            }
+        };
+
+        // Cells in content sized columns decide the width of those columns, so they are measured
+        // before the remaining cells, which are given the width of the columns they span. Cells
+        // in a single intrinsically sized column go first, then those in a single auto column,
+        // which share the width left over by the others, then the cells spanning several columns,
+        // which widen the content sized columns they span when those are too narrow.
+        let pass = |placement: &CellPlacement| {
+            let spanned = &columns[placement.columns()];
+            if !spanned.iter().any(is_content_column) {
+                3
+            } else if placement.column_span > 1 {
+                2
+            } else if spanned[0] == Track::Auto {
+                1
+            } else {
+                0
+            }
+        };
+
+        let mut measured_cells: Vec<Option<MeasuredCell<'a>>> =
+            self.cells.iter().map(|_| None).collect();
+        let mut passes: [Vec<(usize, GridCell<'a>)>; 4] = Default::default();
+        for (index, cell) in self.cells.into_iter().enumerate() {
+            passes[pass(&cell.placement)].push((index, cell));
+        }
+
+        let mut auto_width = 0.;
+        for (pass, cells) in passes.into_iter().enumerate() {
+            match pass {
+                1 => {
+                    auto_width = ((constraints.max.x
+                        - span_size(&column_widths, 0..column_count, column_gap))
+                        / auto_columns.max(1) as f32)
+                        .max(0.)
+                }
+                3 => distribute_fractions(
+                    &columns,
+                    &mut column_widths,
+                    constraints.max.x,
+                    column_gap,
+                ),
+                _ => {}
+            }
+
+            for (index, cell) in cells {
+                let placement = cell.placement;
+                let max_width = match pass {
+                    0 => match columns[placement.column] {
+                        Track::MinContent => cell.widget.min_content_width(ui).unwrap_or(0.),
+                        _ => f32::INFINITY,
+                    },
+                    1 => auto_width,
+                    2 => {
+                        placement
+                            .columns()
+                            .map(|column| match columns[column] {
+                                Track::Auto => auto_width.max(column_widths[column]),
+                                Track::MaxContent => f32::INFINITY,
+                                Track::Fraction(_) if !width_is_bounded => f32::INFINITY,
+                                _ => column_widths[column],
+                            })
+                            .sum::<f32>()
+                            + column_gap * (placement.column_span - 1) as f32
+                    }
+                    _ => span_size(&column_widths, placement.columns(), column_gap),
+                };
+                let (size, widget) = cell.widget.measure(
+                    Constraints::loose(vec2(max_width, max_cell_height(&placement))),
+                    ui,
+                );
+
+                if pass < 3 {
+                    grow_span(
+                        &mut column_widths,
+                        placement.columns(),
+                        |column| is_content_column(&columns[column]),
+                        size.x,
+                        column_gap,
+                    );
+                }
+                measured_cells[index] = Some(MeasuredCell {
+                    placement,
+                    size,
+                    widget,
+                });
+            }
+        }
+
+        let cells: Vec<_> = measured_cells.into_iter().flatten().collect();
+
+        let mut row_heights: Vec<f32> = rows
+            .iter()
+            .enumerate()
+            .map(|(index, track)| match track {
+                Track::Fixed(height) => *height,
+                _ => max_partial(
+                    cells
+                        .iter()
+                        .filter(|it| it.placement.row == index && it.placement.row_span == 1)
+                        .map(|it| it.size.y),
+                )
+                .unwrap_or(0.),
+            })
+            .collect();
+        for cell in cells.iter().filter(|it| it.placement.row_span > 1) {
+            grow_span(
+                &mut row_heights,
+                cell.placement.rows(),
+                |row| !matches!(rows[row], Track::Fixed(_)),
+                cell.size.y,
+                row_gap,
+            );
+        }
+        distribute_fractions(&rows, &mut row_heights, constraints.max.y, row_gap);
+
+        let size = vec2(
+            span_size(&column_widths, 0..column_count, column_gap),
+            span_size(&row_heights, 0..row_count, row_gap),
+        );
+
+        (
+            constraints.constrain(size),
+            MeasuredGrid {
+                column_gap,
+                row_gap,
+                column_widths,
+                row_heights,
+                cells,
+            },
+        )
+    }
+}
+
+struct MeasuredCell<'a> {
+    placement: CellPlacement,
+    size: Vec2,
+    widget: Box<dyn DrawDyn + 'a>,
+}
+
+pub struct MeasuredGrid<'a> {
+    column_gap: f32,
+    row_gap: f32,
+    column_widths: Vec<f32>,
+    row_heights: Vec<f32>,
+    cells: Vec<MeasuredCell<'a>>,
+}
+
+impl<'a> Draw for MeasuredGrid<'a> {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        let mut response = ui.interact(region, ui.auto_id_with("grid"), Sense::hover());
+
+        for cell in self.cells {
+            let placement = cell.placement;
+            let offset = vec2(
+                track_offset(&self.column_widths, placement.column, self.column_gap),
+                track_offset(&self.row_heights, placement.row, self.row_gap),
+            );
+            let size = vec2(
+                span_size(&self.column_widths, placement.columns(), self.column_gap),
+                span_size(&self.row_heights, placement.rows(), self.row_gap),
+            );
+
+            response |= draw_child(
+                cell.widget,
+                Rect::from_min_size(region.min + offset, size),
+                ui,
+            );
+        }
+
+        response
+    }
+}
@@ -1,5 +1,5 @@
-use crate::layout::{Draw, Measure};
-use egui::{Rect, Ui, Vec2};
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Rect, Response, Ui, Vec2};
 
 pub struct LazyMeasuredWidget<W> {
     widget: W,
@@ -20,13 +20,13 @@ struct LazyMeasuredWidgetState {
 impl<W: egui::Widget> Measure for LazyMeasuredWidget<W> {
     type Measured = LazyMeasuredWidgetMeasured<W>;
 
-    fn measure(self, max_size: Vec2, ui: &Ui) -> (Vec2, Self::Measured) {
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
         if let Some(widget) = ui
             .ctx()
             .data(|r| r.get_temp::<LazyMeasuredWidgetState>(self.id))
         {
             return (
-                widget.last_size,
+                constraints.constrain(widget.last_size),
                 LazyMeasuredWidgetMeasured {
                     widget: self.widget,
                     id: self.id,
@@ -35,8 +35,22 @@ impl<W: egui::Widget> Measure for LazyMeasuredWidget<W> {
             );
         }
 
+        let max_size = constraints.max;
+        let guessed_size = Vec2::new(
+            if max_size.x.is_finite() {
+                max_size.x
+            } else {
+                constraints.min.x
+            },
+            if max_size.y.is_finite() {
+                max_size.y
+            } else {
+                constraints.min.y
+            },
+        );
+
         (
-            max_size,
+            guessed_size,
             LazyMeasuredWidgetMeasured {
                 widget: self.widget,
                 id: self.id,
@@ -53,7 +67,7 @@ pub struct LazyMeasuredWidgetMeasured<W> {
 }
 
 impl<W: egui::Widget> Draw for LazyMeasuredWidgetMeasured<W> {
-    fn draw(self, _region: Rect, ui: &mut Ui) {
+    fn draw(self, _region: Rect, ui: &mut Ui) -> Response {
         let response = self.widget.ui(ui);
         let size = response.rect;
 
@@ -69,5 +83,7 @@ impl<W: egui::Widget> Draw for LazyMeasuredWidgetMeasured<W> {
         if self.skip_render {
             ui.ctx().request_discard("layout");
         }
+
+        response
     }
 }
@@ -1,3 +1,19 @@
+pub mod button;
+pub mod checkbox;
+pub mod container;
+pub mod eager;
+pub mod flexible;
 pub mod frame;
+pub mod grid;
 pub mod lazy;
+pub mod padding;
+pub mod radio;
+pub mod scroll;
+pub mod sized_box;
+pub mod slider;
+pub mod stack;
 pub mod text;
+pub mod text_edit;
+pub mod tracked;
+pub mod virtual_list;
+pub mod wrap;
@@ -0,0 +1,53 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Margin, Rect, Response, Ui, Vec2};
+
+pub struct Padding<Child> {
+    margin: Margin,
+    child: Child,
+}
+
+impl<Child: Measure> Padding<Child> {
+    pub fn new(margin: impl Into<Margin>, child: Child) -> Self {
+        Self {
+            margin: margin.into(),
+            child,
+        }
+    }
+}
+
+impl<Child: Measure> Measure for Padding<Child> {
+    type Measured = MeasuredPadding<Child::Measured>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let (child_size, child) = self
+            .child
+            .measure(constraints.deflate(self.margin.sum()), ui);
+
+        (
+            constraints.constrain(child_size + self.margin.sum()),
+            MeasuredPadding {
+                margin: self.margin,
+                child,
+            },
+        )
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        Some(self.child.min_content_width(ui)? + self.margin.sum().x)
+    }
+}
+
+pub struct MeasuredPadding<Child> {
+    margin: Margin,
+    child: Child,
+}
+
+impl<Child: Draw> Draw for MeasuredPadding<Child> {
+    fn baseline(&self) -> Option<f32> {
+        Some(self.child.baseline()? + self.margin.topf())
+    }
+
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        self.child.draw(region - self.margin, ui)
+    }
+}
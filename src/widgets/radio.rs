@@ -0,0 +1,50 @@
+use crate::layout::{Constraints, Draw, Measure};
+use crate::widgets::checkbox::measure_icon_label;
+use egui::{Galley, Rect, Response, Ui, Vec2, WidgetText};
+use std::sync::Arc;
+
+/// An [`egui::RadioButton`] sized from the style's icon spacing and the laid out label.
+pub struct RadioButton {
+    checked: bool,
+    text: WidgetText,
+}
+
+impl RadioButton {
+    pub fn new(checked: bool, text: impl Into<WidgetText>) -> Self {
+        Self {
+            checked,
+            text: text.into(),
+        }
+    }
+}
+
+impl Measure for RadioButton {
+    type Measured = MeasuredRadioButton;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let (size, galley) = measure_icon_label(self.text, constraints, ui);
+
+        (
+            size,
+            MeasuredRadioButton {
+                checked: self.checked,
+                galley,
+            },
+        )
+    }
+}
+
+pub struct MeasuredRadioButton {
+    checked: bool,
+    galley: Option<Arc<Galley>>,
+}
+
+impl Draw for MeasuredRadioButton {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        let text = self
+            .galley
+            .map_or_else(WidgetText::default, WidgetText::from);
+
+        ui.put(region, egui::RadioButton::new(self.checked, text))
+    }
+}
@@ -0,0 +1,92 @@
+use crate::layout::{Constraints, Draw, LayoutDirection, Measure, Vec2Ext};
+use egui::{Id, Rect, Response, ScrollArea, Sense, Ui, Vec2};
+
+/// Hosts its child in an [`egui::ScrollArea`] scrolling along `direction`. The child is measured
+/// without a bound along that axis, while the scroll view itself fits the parent's constraints.
+pub struct Scroll<Child> {
+    direction: LayoutDirection,
+    id_salt: Option<Id>,
+    child: Child,
+}
+
+impl<Child: Measure> Scroll<Child> {
+    pub fn new(direction: LayoutDirection, child: Child) -> Self {
+        Self {
+            direction,
+            id_salt: None,
+            child,
+        }
+    }
+
+    pub fn vertical(child: Child) -> Self {
+        Self::new(LayoutDirection::Column, child)
+    }
+
+    pub fn horizontal(child: Child) -> Self {
+        Self::new(LayoutDirection::Row, child)
+    }
+
+    /// Keeps the scroll position stable when the surrounding tree changes shape.
+    pub fn with_id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+}
+
+impl<Child: Measure> Measure for Scroll<Child> {
+    type Measured = MeasuredScroll<Child::Measured>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let direction = self.direction;
+        let child_constraints =
+            Constraints::loose(constraints.max.with_main_axis(direction, f32::INFINITY));
+        let (child_size, child) = self.child.measure(child_constraints, ui);
+
+        (
+            constraints.constrain(child_size),
+            MeasuredScroll {
+                direction,
+                id_salt: self.id_salt,
+                child_size,
+                child,
+            },
+        )
+    }
+}
+
+pub struct MeasuredScroll<Child> {
+    direction: LayoutDirection,
+    id_salt: Option<Id>,
+    child_size: Vec2,
+    child: Child,
+}
+
+impl<Child: Draw> Draw for MeasuredScroll<Child> {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        let direction = self.direction;
+        // Sibling children share the id of `new_child`, so default to an id which is unique
+        // among them to keep their scroll offsets apart.
+        let id_salt = self.id_salt.unwrap_or_else(|| ui.next_auto_id());
+        let scroll_area = ScrollArea::new([direction.is_horizontal(), !direction.is_horizontal()])
+            .id_salt(id_salt)
+            .auto_shrink([false; 2])
+            .max_width(region.width())
+            .max_height(region.height());
+
+        let mut ui = ui.new_child(egui::UiBuilder::new().max_rect(region).layout(*ui.layout()));
+
+        scroll_area
+            .show(&mut ui, |ui| {
+                let content_size = self.child_size.with_cross_axis(
+                    direction,
+                    self.child_size
+                        .cross_axis(direction)
+                        .max(ui.available_size().cross_axis(direction)),
+                );
+                let (rect, _) = ui.allocate_exact_size(content_size, Sense::hover());
+
+                self.child.draw(rect, ui)
+            })
+            .inner
+    }
+}
@@ -0,0 +1,95 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Rect, Response, Ui, Vec2};
+
+/// Applies additional constraints to its child, on top of the ones given by the parent.
+pub struct ConstrainedBox<Child> {
+    constraints: Constraints,
+    child: Child,
+}
+
+impl<Child: Measure> ConstrainedBox<Child> {
+    pub fn new(constraints: Constraints, child: Child) -> Self {
+        Self { constraints, child }
+    }
+}
+
+impl<Child: Measure> Measure for ConstrainedBox<Child> {
+    type Measured = MeasuredConstrainedBox<Child::Measured>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let constraints = self.constraints.enforce(constraints);
+        let (child_size, child) = self.child.measure(constraints, ui);
+
+        (
+            constraints.constrain(child_size),
+            MeasuredConstrainedBox { child },
+        )
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        let width = self.child.min_content_width(ui)?;
+
+        Some(self.constraints.constrain(Vec2::new(width, 0.)).x)
+    }
+}
+
+/// Forces its child to a fixed width and/or height. Unset axes are left to the child.
+pub struct SizedBox<Child> {
+    width: Option<f32>,
+    height: Option<f32>,
+    child: Child,
+}
+
+impl<Child: Measure> SizedBox<Child> {
+    pub fn new(child: Child) -> Self {
+        Self {
+            width: None,
+            height: None,
+            child,
+        }
+    }
+
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn with_height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+impl<Child: Measure> Measure for SizedBox<Child> {
+    type Measured = MeasuredConstrainedBox<Child::Measured>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let own_constraints = Constraints {
+            min: Vec2::new(self.width.unwrap_or(0.), self.height.unwrap_or(0.)),
+            max: Vec2::new(
+                self.width.unwrap_or(f32::INFINITY),
+                self.height.unwrap_or(f32::INFINITY),
+            ),
+        };
+
+        ConstrainedBox::new(own_constraints, self.child).measure(constraints, ui)
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        self.width.or_else(|| self.child.min_content_width(ui))
+    }
+}
+
+pub struct MeasuredConstrainedBox<Child> {
+    child: Child,
+}
+
+impl<Child: Draw> Draw for MeasuredConstrainedBox<Child> {
+    fn baseline(&self) -> Option<f32> {
+        self.child.baseline()
+    }
+
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        self.child.draw(region, ui)
+    }
+}
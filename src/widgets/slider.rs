@@ -0,0 +1,48 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::emath::Numeric;
+use egui::{Rect, Response, TextStyle, Ui, Vec2, vec2};
+use std::ops::RangeInclusive;
+
+/// A horizontal [`egui::Slider`] without the value field, sized from the style's slider width.
+pub struct Slider<'a, Num> {
+    value: &'a mut Num,
+    range: RangeInclusive<Num>,
+}
+
+impl<'a, Num: Numeric> Slider<'a, Num> {
+    pub fn new(value: &'a mut Num, range: RangeInclusive<Num>) -> Self {
+        Self { value, range }
+    }
+}
+
+impl<'a, Num: Numeric> Measure for Slider<'a, Num> {
+    type Measured = MeasuredSlider<'a, Num>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let thickness = ui
+            .text_style_height(&TextStyle::Body)
+            .max(ui.spacing().interact_size.y);
+
+        (
+            constraints.constrain(vec2(ui.spacing().slider_width, thickness)),
+            MeasuredSlider {
+                value: self.value,
+                range: self.range,
+            },
+        )
+    }
+}
+
+pub struct MeasuredSlider<'a, Num> {
+    value: &'a mut Num,
+    range: RangeInclusive<Num>,
+}
+
+impl<'a, Num: Numeric> Draw for MeasuredSlider<'a, Num> {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        ui.put(
+            region,
+            egui::Slider::new(self.value, self.range).show_value(false),
+        )
+    }
+}
@@ -0,0 +1,106 @@
+use crate::layout::{Constraints, Draw, DrawDyn, Measure, MeasureDyn, draw_child, max_partial};
+use egui::{Align2, Rect, Response, Sense, Ui, Vec2};
+
+struct StackChild<'a> {
+    align: Align2,
+    /// Offset from the anchored position. Children with an offset don't contribute to the size of
+    /// the stack, so they can hang over its edges.
+    offset: Option<Vec2>,
+    widget: Box<dyn MeasureDyn<'a> + 'a>,
+}
+
+/// Draws children on top of each other within the same region, in the order they were added.
+#[derive(Default)]
+pub struct Stack<'a> {
+    children: Vec<StackChild<'a>>,
+}
+
+impl<'a> Stack<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_child(self, child: impl Measure + 'a) -> Self {
+        self.with_aligned_child(Align2::LEFT_TOP, child)
+    }
+
+    pub fn with_aligned_child(mut self, align: Align2, child: impl Measure + 'a) -> Self {
+        self.children.push(StackChild {
+            align,
+            offset: None,
+            widget: Box::new(child),
+        });
+        self
+    }
+
+    pub fn with_positioned_child(
+        mut self,
+        align: Align2,
+        offset: Vec2,
+        child: impl Measure + 'a,
+    ) -> Self {
+        self.children.push(StackChild {
+            align,
+            offset: Some(offset),
+            widget: Box::new(child),
+        });
+        self
+    }
+}
+
+impl<'a> Measure for Stack<'a> {
+    type Measured = MeasuredStack<'a>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let children: Vec<_> = self
+            .children
+            .into_iter()
+            .map(|it| {
+                let (size, widget) = it.widget.measure(constraints.loosen(), ui);
+
+                MeasuredStackChild {
+                    align: it.align,
+                    offset: it.offset,
+                    size,
+                    widget,
+                }
+            })
+            .collect();
+
+        let in_flow = || children.iter().filter(|it| it.offset.is_none());
+        let size = Vec2::new(
+            max_partial(in_flow().map(|it| it.size.x)).unwrap_or(0.),
+            max_partial(in_flow().map(|it| it.size.y)).unwrap_or(0.),
+        );
+
+        (constraints.constrain(size), MeasuredStack { children })
+    }
+}
+
+struct MeasuredStackChild<'a> {
+    align: Align2,
+    offset: Option<Vec2>,
+    size: Vec2,
+    widget: Box<dyn DrawDyn + 'a>,
+}
+
+pub struct MeasuredStack<'a> {
+    children: Vec<MeasuredStackChild<'a>>,
+}
+
+impl<'a> Draw for MeasuredStack<'a> {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        let mut response = ui.interact(region, ui.auto_id_with("stack"), Sense::hover());
+
+        for child in self.children {
+            let rect = child
+                .align
+                .align_size_within_rect(child.size, region)
+                .translate(child.offset.unwrap_or(Vec2::ZERO));
+
+            response |= draw_child(child.widget, rect, ui);
+        }
+
+        response
+    }
+}
@@ -1,11 +1,12 @@
-use crate::layout::{Draw, Measure};
+use crate::layout::{Constraints, Draw, Measure, TextDirection, max_partial};
 use egui::text::TextWrapping;
-use egui::{Align, FontSelection, Galley, Rect, Ui, Vec2, WidgetText, epaint};
+use egui::{Align, FontSelection, Galley, Rect, Response, Sense, Ui, Vec2, WidgetText, epaint};
 use std::sync::Arc;
 
 pub struct Text {
     text: WidgetText,
     wrapping: Option<TextWrapping>,
+    text_direction: Option<TextDirection>,
 }
 
 impl Text {
@@ -13,26 +14,63 @@ impl Text {
         Self {
             text: text.into(),
             wrapping: Some(TextWrapping::default()),
+            text_direction: None,
         }
     }
+
+    /// Overrides the direction inherited from the enclosing layout.
+    pub fn with_text_direction(mut self, text_direction: TextDirection) -> Self {
+        self.text_direction = Some(text_direction);
+        self
+    }
 }
 
 impl Measure for Text {
     type Measured = MeasuredText;
 
-    fn measure(self, max_size: Vec2, ui: &Ui) -> (Vec2, MeasuredText) {
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, MeasuredText) {
         let mut layout_job =
             self.text
                 .into_layout_job(ui.style(), FontSelection::Default, Align::Min);
+        layout_job.halign = match self
+            .text_direction
+            .unwrap_or_else(|| TextDirection::from_ui(ui))
+        {
+            TextDirection::LeftToRight => Align::Min,
+            TextDirection::RightToLeft => Align::Max,
+        };
 
         if let Some(mut wrapping) = self.wrapping {
-            wrapping.max_width = max_size.x;
+            wrapping.max_width = constraints.max.x;
             layout_job.wrap = wrapping;
         }
 
         let galley = ui.fonts(|it| it.layout_job(layout_job));
 
-        (galley.size(), MeasuredText { galley })
+        (
+            constraints.constrain(galley.size()),
+            MeasuredText { galley },
+        )
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        let layout_job =
+            self.text
+                .clone()
+                .into_layout_job(ui.style(), FontSelection::Default, Align::Min);
+        let galley = ui.fonts(|it| it.layout_job(layout_job));
+
+        if self.wrapping.is_none() {
+            return Some(galley.size().x);
+        }
+
+        let words = galley.rows.iter().flat_map(|row| {
+            row.glyphs
+                .split(|it| it.chr.is_whitespace())
+                .filter_map(|word| Some(word.last()?.max_x() - word.first()?.pos.x))
+        });
+
+        Some(max_partial(words).unwrap_or(0.))
     }
 }
 
@@ -41,10 +79,21 @@ pub struct MeasuredText {
 }
 
 impl Draw for MeasuredText {
-    fn draw(self, rect: Rect, ui: &mut Ui) {
+    fn baseline(&self) -> Option<f32> {
+        let first_row = self.galley.rows.first()?;
+
+        max_partial(first_row.glyphs.iter().map(|it| it.pos.y))
+    }
+
+    fn draw(self, rect: Rect, ui: &mut Ui) -> Response {
         let text_color = ui.style().visuals.text_color();
 
+        // Rows aligned to the right are laid out left of the galley's origin.
+        let position = rect.min - self.galley.rect.min.to_vec2();
+
         ui.painter()
-            .add(epaint::TextShape::new(rect.min, self.galley, text_color));
+            .add(epaint::TextShape::new(position, self.galley, text_color));
+
+        ui.allocate_rect(rect, Sense::hover())
     }
 }
@@ -0,0 +1,63 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Margin, Rect, Response, TextStyle, Ui, Vec2, vec2};
+
+/// The margin `egui::TextEdit` uses around its text by default.
+const MARGIN: Margin = Margin::symmetric(4, 2);
+
+/// A single line [`egui::TextEdit`] sized from the style's text edit width and the row height of
+/// the body font.
+pub struct TextEdit<'a> {
+    text: &'a mut String,
+    hint_text: Option<String>,
+}
+
+impl<'a> TextEdit<'a> {
+    pub fn singleline(text: &'a mut String) -> Self {
+        Self {
+            text,
+            hint_text: None,
+        }
+    }
+
+    pub fn with_hint_text(mut self, hint_text: impl Into<String>) -> Self {
+        self.hint_text = Some(hint_text.into());
+        self
+    }
+}
+
+impl<'a> Measure for TextEdit<'a> {
+    type Measured = MeasuredTextEdit<'a>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let font_id = TextStyle::Body.resolve(ui.style());
+        let row_height = ui.fonts(|it| it.row_height(&font_id));
+        let width = ui
+            .spacing()
+            .text_edit_width
+            .min(constraints.max.x - MARGIN.sum().x);
+
+        (
+            constraints.constrain(vec2(width, row_height) + MARGIN.sum()),
+            MeasuredTextEdit {
+                text: self.text,
+                hint_text: self.hint_text,
+            },
+        )
+    }
+}
+
+pub struct MeasuredTextEdit<'a> {
+    text: &'a mut String,
+    hint_text: Option<String>,
+}
+
+impl<'a> Draw for MeasuredTextEdit<'a> {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        let mut text_edit = egui::TextEdit::singleline(self.text).margin(MARGIN);
+        if let Some(hint_text) = self.hint_text {
+            text_edit = text_edit.hint_text(hint_text);
+        }
+
+        ui.put(region, text_edit)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::layout::{Constraints, Draw, Flex, Measure};
+use egui::{Rect, Response, Ui, Vec2};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Receives the response of a [`Tracked`] widget when it's drawn, as containers only return the
+/// union of their children's responses.
+#[derive(Clone, Default)]
+pub struct ResponseHandle(Rc<RefCell<Option<Response>>>);
+
+impl ResponseHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Response of the last draw, `None` until the widget has been drawn.
+    pub fn response(&self) -> Option<Response> {
+        self.0.borrow().clone()
+    }
+
+    pub fn clicked(&self) -> bool {
+        self.0.borrow().as_ref().is_some_and(Response::clicked)
+    }
+}
+
+/// Reports the response of `child` through a [`ResponseHandle`], so a node deep in a tree can be
+/// told apart from its siblings.
+pub struct Tracked<Child> {
+    handle: ResponseHandle,
+    child: Child,
+}
+
+impl<Child: Measure> Tracked<Child> {
+    pub fn new(handle: &ResponseHandle, child: Child) -> Self {
+        Self {
+            handle: handle.clone(),
+            child,
+        }
+    }
+}
+
+impl<Child: Measure> Measure for Tracked<Child> {
+    type Measured = MeasuredTracked<Child::Measured>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let (size, child) = self.child.measure(constraints, ui);
+
+        (
+            size,
+            MeasuredTracked {
+                handle: self.handle,
+                child,
+            },
+        )
+    }
+
+    fn flex(&self) -> Flex {
+        self.child.flex()
+    }
+
+    fn min_content_width(&self, ui: &Ui) -> Option<f32> {
+        self.child.min_content_width(ui)
+    }
+}
+
+pub struct MeasuredTracked<Child> {
+    handle: ResponseHandle,
+    child: Child,
+}
+
+impl<Child: Draw> Draw for MeasuredTracked<Child> {
+    fn baseline(&self) -> Option<f32> {
+        self.child.baseline()
+    }
+
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        let response = self.child.draw(region, ui);
+        *self.handle.0.borrow_mut() = Some(response.clone());
+        response
+    }
+}
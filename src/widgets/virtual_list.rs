@@ -0,0 +1,195 @@
+use crate::layout::{Constraints, Draw, Measure};
+use egui::{Id, Rect, Response, ScrollArea, Sense, Ui, Vec2, vec2};
+
+/// Row heights of a [`VirtualList`] from previous frames, for the width they were measured at.
+#[derive(Clone, Default)]
+struct VirtualListState {
+    width: f32,
+    heights: Vec<Option<f32>>,
+}
+
+impl VirtualListState {
+    fn load(ui: &Ui, id: Id, width: f32, item_count: usize) -> Self {
+        let mut state = ui
+            .ctx()
+            .data(|it| it.get_temp::<VirtualListState>(id))
+            .filter(|it| it.width == width)
+            .unwrap_or_else(|| VirtualListState {
+                width,
+                heights: vec![],
+            });
+        state.heights.resize(item_count, None);
+        state
+    }
+
+    /// Height used for rows which have not been measured yet.
+    fn estimated_height(&self, ui: &Ui) -> f32 {
+        let (count, total) = self
+            .heights
+            .iter()
+            .flatten()
+            .fold((0, 0.), |(count, total), height| {
+                (count + 1, total + height)
+            });
+
+        if count > 0 {
+            total / count as f32
+        } else {
+            ui.spacing().interact_size.y
+        }
+    }
+
+    /// Offset of every row from the top of the list, followed by the total height.
+    fn offsets(&self, ui: &Ui) -> Vec<f32> {
+        let estimated_height = self.estimated_height(ui);
+
+        std::iter::once(0.)
+            .chain(self.heights.iter().scan(0., |offset, height| {
+                *offset += height.unwrap_or(estimated_height);
+                Some(*offset)
+            }))
+            .collect()
+    }
+}
+
+/// Size a [`VirtualList`] takes along an axis it isn't bounded on by default.
+const UNBOUNDED_SIZE: Vec2 = Vec2::new(200., 200.);
+
+/// A vertically scrolling list which only builds and measures the rows around the viewport.
+/// Heights of rows which haven't been seen yet are estimated from the ones which have.
+///
+/// The list should be given a bounded height, e.g. as a [`Flex::grow`](crate::layout::Flex::grow)
+/// child of a column. Growing to fit its rows would put all of them in the viewport, so along an
+/// unbounded axis it takes a fixed size instead, see [`VirtualList::with_unbounded_size`].
+pub struct VirtualList<F> {
+    id: Id,
+    item_count: usize,
+    overscan: usize,
+    unbounded_size: Vec2,
+    build_row: F,
+}
+
+impl<F, Row> VirtualList<F>
+where
+    F: FnMut(usize) -> Row,
+    Row: Measure,
+{
+    pub fn new(id: Id, item_count: usize, build_row: F) -> Self {
+        Self {
+            id,
+            item_count,
+            overscan: 2,
+            unbounded_size: UNBOUNDED_SIZE,
+            build_row,
+        }
+    }
+
+    /// Number of rows built beyond each edge of the viewport.
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Size taken along an axis the constraints don't bound, 200 points on either axis by default.
+    pub fn with_unbounded_size(mut self, size: Vec2) -> Self {
+        self.unbounded_size = size;
+        self
+    }
+}
+
+impl<F, Row> Measure for VirtualList<F>
+where
+    F: FnMut(usize) -> Row,
+    Row: Measure,
+{
+    type Measured = MeasuredVirtualList<F>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let width = if constraints.max.x.is_finite() {
+            constraints.max.x
+        } else {
+            self.unbounded_size.x
+        };
+        let state = VirtualListState::load(ui, self.id, width, self.item_count);
+        let content_height = state.offsets(ui).last().copied().unwrap_or(0.);
+        let height = if constraints.max.y.is_finite() {
+            content_height
+        } else {
+            content_height.min(self.unbounded_size.y)
+        };
+
+        (
+            constraints.constrain(vec2(width, height)),
+            MeasuredVirtualList {
+                id: self.id,
+                item_count: self.item_count,
+                overscan: self.overscan,
+                build_row: self.build_row,
+            },
+        )
+    }
+}
+
+pub struct MeasuredVirtualList<F> {
+    id: Id,
+    item_count: usize,
+    overscan: usize,
+    build_row: F,
+}
+
+impl<F, Row> Draw for MeasuredVirtualList<F>
+where
+    F: FnMut(usize) -> Row,
+    Row: Measure,
+{
+    fn draw(mut self, region: Rect, ui: &mut Ui) -> Response {
+        let mut ui = ui.new_child(egui::UiBuilder::new().max_rect(region).layout(*ui.layout()));
+        let mut state = VirtualListState::load(&ui, self.id, region.width(), self.item_count);
+        let offsets = state.offsets(&ui);
+        let mut response = ui.interact(region, ui.auto_id_with("virtual_list"), Sense::hover());
+
+        ScrollArea::vertical()
+            .id_salt(self.id)
+            .auto_shrink([false; 2])
+            .show_viewport(&mut ui, |ui, viewport| {
+                ui.set_height(offsets[self.item_count]);
+
+                let origin = ui.max_rect().min;
+                let first_row = offsets[1..]
+                    .partition_point(|it| *it < viewport.min.y)
+                    .saturating_sub(self.overscan);
+                let last_row = (offsets[..self.item_count]
+                    .partition_point(|it| *it < viewport.max.y)
+                    + self.overscan)
+                    .min(self.item_count);
+
+                let mut offset = offsets[first_row];
+                for index in first_row..last_row {
+                    let (size, row) = (self.build_row)(index)
+                        .measure(Constraints::loose(vec2(region.width(), f32::INFINITY)), ui);
+                    let rect = Rect::from_min_size(origin + vec2(0., offset), size);
+
+                    let mut row_ui = ui.new_child(
+                        egui::UiBuilder::new()
+                            .id_salt(self.id.with(index))
+                            .max_rect(rect)
+                            .layout(*ui.layout()),
+                    );
+                    response |= row.draw(rect, &mut row_ui);
+
+                    state.heights[index] = Some(size.y);
+                    offset += size.y;
+                }
+            });
+
+        if state.offsets(&ui) != offsets {
+            // Rows were measured at a different height than estimated, so the rows following them
+            // were placed at the wrong offset.
+            ui.ctx().request_repaint();
+        }
+
+        ui.ctx().data_mut(|it| it.insert_temp(self.id, state));
+
+        response
+    }
+}
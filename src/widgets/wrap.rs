@@ -0,0 +1,214 @@
+use crate::layout::{
+    Alignment, Constraints, Draw, DrawDyn, LayoutDirection, MainAxisAlignment, Measure, MeasureDyn,
+    TextDirection, Vec2Ext, baseline_offset, draw_child, max_partial,
+};
+use egui::{Rect, Response, Sense, Ui, Vec2};
+
+pub struct WrapParams {
+    pub direction: LayoutDirection,
+    /// Placement of children within each run.
+    pub main_axis_alignment: MainAxisAlignment,
+    /// Placement of children within the cross axis extent of their run.
+    pub cross_axis_alignment: Alignment,
+    /// Main axis space between adjacent children of a run. Defaults to the style's
+    /// `item_spacing`.
+    pub gap: Option<f32>,
+    /// Cross axis space between adjacent runs. Defaults to the style's `item_spacing`.
+    pub run_gap: Option<f32>,
+}
+
+impl Default for WrapParams {
+    fn default() -> Self {
+        WrapParams {
+            direction: LayoutDirection::Row,
+            main_axis_alignment: MainAxisAlignment::Start,
+            cross_axis_alignment: Alignment::Start,
+            gap: None,
+            run_gap: None,
+        }
+    }
+}
+
+/// Places children along the main axis like a [`crate::layout::Layout`], starting a new run
+/// whenever the next child would not fit.
+pub struct Wrap<'a> {
+    params: WrapParams,
+    children: Vec<Box<dyn MeasureDyn<'a> + 'a>>,
+}
+
+impl<'a> Wrap<'a> {
+    pub fn new(params: WrapParams) -> Self {
+        Self {
+            params,
+            children: vec![],
+        }
+    }
+
+    pub fn with_child(mut self, child: impl Measure + 'a) -> Self {
+        self.children.push(Box::new(child));
+        self
+    }
+}
+
+impl<'a> Measure for Wrap<'a> {
+    type Measured = MeasuredWrap<'a>;
+
+    fn measure(self, constraints: Constraints, ui: &Ui) -> (Vec2, Self::Measured) {
+        let direction = self.params.direction;
+        let item_spacing = ui.style().spacing.item_spacing;
+        let gap = self
+            .params
+            .gap
+            .unwrap_or_else(|| item_spacing.main_axis(direction));
+        let run_gap = self
+            .params
+            .run_gap
+            .unwrap_or_else(|| item_spacing.cross_axis(direction));
+        let main_axis_size = constraints.max.main_axis(direction);
+        let child_constraints = Constraints::loose(
+            Vec2::ZERO
+                .with_main_axis(direction, main_axis_size)
+                .with_cross_axis(direction, f32::INFINITY),
+        );
+
+        let mut runs: Vec<Run<'a>> = vec![];
+        for child in self.children {
+            let (size, widget) = child.measure(child_constraints, ui);
+            let child = WrapChild { size, widget };
+
+            match runs.last_mut() {
+                Some(run)
+                    if run.main_axis_size + gap + size.main_axis(direction) <= main_axis_size =>
+                {
+                    run.main_axis_size += gap + size.main_axis(direction);
+                    run.children.push(child);
+                }
+                _ => runs.push(Run {
+                    main_axis_size: size.main_axis(direction),
+                    children: vec![child],
+                }),
+            }
+        }
+
+        let main_axis_extent = max_partial(runs.iter().map(|it| it.main_axis_size)).unwrap_or(0.);
+        let cross_axis_extent = runs
+            .iter()
+            .map(|it| it.cross_axis_size(direction, &self.params.cross_axis_alignment))
+            .sum::<f32>()
+            + run_gap * runs.len().saturating_sub(1) as f32;
+
+        (
+            constraints.constrain(
+                Vec2::ZERO
+                    .with_main_axis(direction, main_axis_extent)
+                    .with_cross_axis(direction, cross_axis_extent),
+            ),
+            MeasuredWrap {
+                params: self.params,
+                gap,
+                run_gap,
+                runs,
+            },
+        )
+    }
+}
+
+struct WrapChild<'a> {
+    size: Vec2,
+    widget: Box<dyn DrawDyn + 'a>,
+}
+
+struct Run<'a> {
+    /// Main axis size of the children including the gaps between them.
+    main_axis_size: f32,
+    children: Vec<WrapChild<'a>>,
+}
+
+impl Run<'_> {
+    fn baseline(&self, direction: LayoutDirection, alignment: &Alignment) -> Option<f32> {
+        match alignment {
+            Alignment::Baseline if direction.is_horizontal() => {
+                max_partial(self.children.iter().filter_map(|it| it.widget.baseline()))
+            }
+            _ => None,
+        }
+    }
+
+    fn cross_axis_size(&self, direction: LayoutDirection, alignment: &Alignment) -> f32 {
+        let baseline = self.baseline(direction, alignment);
+
+        max_partial(self.children.iter().map(|it| {
+            baseline_offset(baseline, it.widget.baseline()) + it.size.cross_axis(direction)
+        }))
+        .unwrap_or(0.)
+    }
+}
+
+pub struct MeasuredWrap<'a> {
+    params: WrapParams,
+    gap: f32,
+    run_gap: f32,
+    runs: Vec<Run<'a>>,
+}
+
+impl<'a> Draw for MeasuredWrap<'a> {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        let direction = self.params.direction;
+        let alignment = &self.params.cross_axis_alignment;
+        let text_direction = TextDirection::from_ui(ui);
+        let mut current_cross_axis = 0.;
+        let mut response = ui.interact(region, ui.auto_id_with("wrap"), Sense::hover());
+
+        for run in self.runs {
+            let run_cross_axis_size = run.cross_axis_size(direction, alignment);
+            let baseline = run.baseline(direction, alignment);
+            let (starting_offset, spacing_between) = self.params.main_axis_alignment.distribute(
+                region.size().main_axis(direction) - run.main_axis_size,
+                run.children.len(),
+            );
+
+            let mut current_main_axis = starting_offset;
+            for child in run.children {
+                let size = match alignment {
+                    Alignment::Stretch => {
+                        child.size.with_cross_axis(direction, run_cross_axis_size)
+                    }
+                    _ => child.size,
+                };
+                let cross_axis_offset = match alignment {
+                    Alignment::Start | Alignment::Stretch => 0.,
+                    Alignment::End => run_cross_axis_size - size.cross_axis(direction),
+                    Alignment::Center => (run_cross_axis_size - size.cross_axis(direction)) / 2.0,
+                    Alignment::Baseline => baseline_offset(baseline, child.widget.baseline()),
+                };
+                let main_axis = direction.place_main_axis(
+                    text_direction,
+                    region.size().main_axis(direction),
+                    current_main_axis,
+                    size.main_axis(direction),
+                );
+                let cross_axis = direction.place_cross_axis(
+                    text_direction,
+                    region.size().cross_axis(direction),
+                    current_cross_axis + cross_axis_offset,
+                    size.cross_axis(direction),
+                );
+                let rect = Rect::from_min_size(
+                    region.min
+                        + Vec2::ZERO
+                            .with_main_axis(direction, main_axis)
+                            .with_cross_axis(direction, cross_axis),
+                    size,
+                );
+
+                current_main_axis += child.size.main_axis(direction) + self.gap + spacing_between;
+
+                response |= draw_child(child.widget, rect, ui);
+            }
+
+            current_cross_axis += run_cross_axis_size + self.run_gap;
+        }
+
+        response
+    }
+}
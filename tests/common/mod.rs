@@ -0,0 +1,79 @@
+// Each test crate uses its own subset of the helpers.
+#![allow(dead_code)]
+
+use egui::{CentralPanel, Context, RawInput, Rect, Response, Sense, Ui, Vec2, vec2};
+use egui_layout::layout::{Constraints, Draw, Measure};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Runs `add_contents` inside a central panel of a headless frame.
+pub fn with_ui(mut add_contents: impl FnMut(&mut Ui)) {
+    let ctx = Context::default();
+    let _ = ctx.run(RawInput::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| add_contents(ui));
+    });
+}
+
+/// Content which wraps like text, keeping its area when narrowed, and records where it's drawn.
+#[derive(Clone)]
+pub struct Probe {
+    size: Vec2,
+    wraps: bool,
+    baseline: Option<f32>,
+    rect: Rc<Cell<Rect>>,
+}
+
+impl Probe {
+    pub fn fixed(width: f32, height: f32) -> Self {
+        Self {
+            size: vec2(width, height),
+            wraps: false,
+            baseline: None,
+            rect: Rc::new(Cell::new(Rect::NOTHING)),
+        }
+    }
+
+    pub fn wrapping(width: f32, height: f32) -> Self {
+        Self {
+            wraps: true,
+            ..Self::fixed(width, height)
+        }
+    }
+
+    pub fn with_baseline(self, baseline: f32) -> Self {
+        Self {
+            baseline: Some(baseline),
+            ..self
+        }
+    }
+
+    pub fn rect(&self) -> Rect {
+        self.rect.get()
+    }
+}
+
+impl Measure for Probe {
+    type Measured = Probe;
+
+    fn measure(self, constraints: Constraints, _ui: &Ui) -> (Vec2, Self::Measured) {
+        let size = if self.wraps && self.size.x > constraints.max.x {
+            let width = constraints.max.x.max(1.);
+            vec2(width, self.size.x * self.size.y / width)
+        } else {
+            self.size
+        };
+
+        (constraints.constrain(size), self)
+    }
+}
+
+impl Draw for Probe {
+    fn draw(self, region: Rect, ui: &mut Ui) -> Response {
+        self.rect.set(region);
+        ui.allocate_rect(region, Sense::hover())
+    }
+
+    fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::with_ui;
+use egui::{Id, Ui, vec2};
+use egui_layout::layout::{Constraints, Measure};
+use egui_layout::widgets::eager::EagerMeasuredWidget;
+
+#[test]
+fn eager_widget_measures_all_added_content() {
+    with_ui(|ui| {
+        let constraints = Constraints::loose(vec2(400., 400.));
+        let (button_size, _) =
+            EagerMeasuredWidget::new(|ui: &mut Ui| ui.button("open"), Id::new("button"))
+                .measure(constraints, ui);
+        let (size, _) = EagerMeasuredWidget::new(
+            |ui: &mut Ui| {
+                ui.label("a much longer first label");
+                ui.label("second label");
+                ui.button("open")
+            },
+            Id::new("contents"),
+        )
+        .measure(constraints, ui);
+
+        assert!(size.x > button_size.x, "{size:?} vs {button_size:?}");
+        assert!(size.y > 2. * button_size.y, "{size:?} vs {button_size:?}");
+    });
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::with_ui;
+use egui::{Pos2, Rect, Ui, Vec2, vec2};
+use egui_layout::layout::{
+    Constraints, Draw, Layout, LayoutDirection, LayoutParams, MainAxisAlignment, Measure,
+};
+
+const DIRECTIONS: [LayoutDirection; 4] = [
+    LayoutDirection::Row,
+    LayoutDirection::Column,
+    LayoutDirection::RowReverse,
+    LayoutDirection::ColumnReverse,
+];
+
+fn shape_count(ui: &Ui) -> usize {
+    ui.ctx().graphics(|it| {
+        it.get(ui.layer_id())
+            .map_or(0, |paint_list| paint_list.next_idx().0)
+    })
+}
+
+fn empty_layout<'a>(direction: LayoutDirection) -> Layout<'a> {
+    Layout::new(LayoutParams {
+        direction,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn empty_layout_measures_to_zero() {
+    with_ui(|ui| {
+        for direction in DIRECTIONS {
+            let (size, _) =
+                empty_layout(direction).measure(Constraints::loose(vec2(200., 100.)), ui);
+
+            assert_eq!(size, Vec2::ZERO, "{direction:?}");
+        }
+    });
+}
+
+#[test]
+fn empty_layout_measures_to_min_constraints() {
+    with_ui(|ui| {
+        for direction in DIRECTIONS {
+            let constraints = Constraints::new(vec2(20., 10.), vec2(200., 100.));
+            let (size, _) = empty_layout(direction).measure(constraints, ui);
+
+            assert_eq!(size, vec2(20., 10.), "{direction:?}");
+        }
+    });
+}
+
+#[test]
+fn empty_layout_measures_to_zero_when_unbounded() {
+    with_ui(|ui| {
+        for direction in DIRECTIONS {
+            let (size, _) = empty_layout(direction).measure(Constraints::UNBOUNDED, ui);
+
+            assert_eq!(size, Vec2::ZERO, "{direction:?}");
+        }
+    });
+}
+
+#[test]
+fn empty_layout_draws_nothing() {
+    with_ui(|ui| {
+        for direction in DIRECTIONS {
+            for main_axis_alignment in [
+                MainAxisAlignment::Start,
+                MainAxisAlignment::End,
+                MainAxisAlignment::Center,
+                MainAxisAlignment::SpaceBetween,
+                MainAxisAlignment::SpaceAround,
+                MainAxisAlignment::SpaceEvenly,
+            ] {
+                let (size, measured) = Layout::new(LayoutParams {
+                    direction,
+                    main_axis_alignment,
+                    ..Default::default()
+                })
+                .measure(Constraints::loose(vec2(200., 100.)), ui);
+
+                let shapes_before = shape_count(ui);
+                measured.draw(Rect::from_min_size(Pos2::ZERO, size), ui);
+                let shapes_after = shape_count(ui);
+
+                assert_eq!(shapes_before, shapes_after, "{direction:?}");
+            }
+        }
+    });
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use common::{Probe, with_ui};
+use egui::{Pos2, Rect, Ui, Vec2, vec2};
+use egui_layout::layout::{Constraints, Draw, Measure};
+use egui_layout::widgets::grid::{Grid, GridParams, Track};
+use egui_layout::widgets::text::Text;
+
+fn params(columns: Vec<Track>, rows: Vec<Track>) -> GridParams {
+    GridParams {
+        columns,
+        rows,
+        column_gap: Some(10.),
+        row_gap: Some(10.),
+    }
+}
+
+fn show(grid: Grid, max: Vec2, ui: &mut Ui) -> Vec2 {
+    let (size, measured) = grid.measure(Constraints::loose(max), ui);
+    measured.draw(Rect::from_min_size(Pos2::ZERO, size), ui);
+    size
+}
+
+#[test]
+fn fixed_and_fraction_columns_split_the_width() {
+    with_ui(|ui| {
+        let cells = [
+            Probe::fixed(5., 20.),
+            Probe::fixed(5., 20.),
+            Probe::fixed(5., 20.),
+        ];
+        let grid = Grid::new(params(
+            vec![Track::Fixed(50.), Track::Fraction(1.), Track::Fraction(3.)],
+            vec![Track::Fixed(30.)],
+        ))
+        .with_cell(0, 0, cells[0].clone())
+        .with_cell(1, 0, cells[1].clone())
+        .with_cell(2, 0, cells[2].clone());
+
+        let size = show(grid, vec2(250., 100.), ui);
+
+        assert_eq!(size, vec2(250., 30.));
+        assert_eq!(
+            cells.each_ref().map(|it| it.rect().width()),
+            [50., 45., 135.]
+        );
+        assert_eq!(cells.each_ref().map(|it| it.rect().min.x), [0., 60., 115.]);
+    });
+}
+
+#[test]
+fn auto_columns_share_the_leftover_width() {
+    with_ui(|ui| {
+        let cells = [Probe::wrapping(500., 10.), Probe::wrapping(500., 10.)];
+        let grid = Grid::new(params(vec![Track::Auto, Track::Auto], vec![]))
+            .with_cell(0, 0, cells[0].clone())
+            .with_cell(1, 0, cells[1].clone());
+
+        let size = show(grid, vec2(300., f32::INFINITY), ui);
+
+        assert_eq!(size.x, 300.);
+        assert_eq!(cells.each_ref().map(|it| it.rect().width()), [145., 145.]);
+        assert!(cells[1].rect().max.x <= 300.);
+    });
+}
+
+#[test]
+fn auto_columns_fit_narrow_content() {
+    with_ui(|ui| {
+        let cells = [Probe::fixed(40., 10.), Probe::wrapping(60., 10.)];
+        let grid = Grid::new(params(vec![Track::Auto, Track::Auto], vec![]))
+            .with_cell(0, 0, cells[0].clone())
+            .with_cell(1, 0, cells[1].clone());
+
+        let size = show(grid, vec2(300., f32::INFINITY), ui);
+
+        assert_eq!(size, vec2(110., 10.));
+    });
+}
+
+#[test]
+fn spanning_cell_widens_the_columns_it_spans() {
+    with_ui(|ui| {
+        let cell = Probe::wrapping(100., 10.);
+        let grid = Grid::new(params(vec![Track::Auto, Track::Auto], vec![])).with_spanning_cell(
+            0,
+            0,
+            2,
+            1,
+            cell.clone(),
+        );
+
+        let size = show(grid, vec2(300., f32::INFINITY), ui);
+
+        assert_eq!(size, vec2(100., 10.));
+        assert_eq!(cell.rect().size(), vec2(100., 10.));
+    });
+}
+
+#[test]
+fn spanning_cell_only_widens_content_sized_columns() {
+    with_ui(|ui| {
+        let cells = [Probe::fixed(20., 10.), Probe::fixed(100., 10.)];
+        let grid = Grid::new(params(vec![Track::Fixed(20.), Track::Auto], vec![]))
+            .with_cell(0, 0, cells[0].clone())
+            .with_spanning_cell(0, 1, 2, 1, cells[1].clone());
+
+        let size = show(grid, vec2(300., f32::INFINITY), ui);
+
+        assert_eq!(size, vec2(100., 30.));
+        assert_eq!(cells[0].rect().width(), 20.);
+        assert_eq!(cells[1].rect().width(), 100.);
+    });
+}
+
+#[test]
+fn spanning_cell_grows_the_rows_it_spans() {
+    with_ui(|ui| {
+        let cells = [
+            Probe::fixed(20., 20.),
+            Probe::fixed(20., 20.),
+            Probe::fixed(20., 100.),
+        ];
+        let grid = Grid::new(params(vec![Track::Auto, Track::Auto], vec![]))
+            .with_cell(0, 0, cells[0].clone())
+            .with_cell(0, 1, cells[1].clone())
+            .with_spanning_cell(1, 0, 1, 2, cells[2].clone());
+
+        let size = show(grid, vec2(300., f32::INFINITY), ui);
+
+        assert_eq!(size, vec2(50., 100.));
+        assert_eq!(cells[0].rect().height(), 45.);
+        assert_eq!(cells[1].rect().min.y, 55.);
+        assert_eq!(cells[2].rect().height(), 100.);
+    });
+}
+
+#[test]
+fn min_content_columns_fit_the_longest_word() {
+    with_ui(|ui| {
+        let (word_size, _) = Text::new("wonderful").measure(Constraints::UNBOUNDED, ui);
+        let next = Probe::fixed(20., 20.);
+        let grid = Grid::new(params(vec![Track::MinContent, Track::Auto], vec![]))
+            .with_cell(0, 0, Text::new("hello wonderful world"))
+            .with_cell(1, 0, next.clone());
+
+        show(grid, vec2(400., 400.), ui);
+
+        // Galley sizes are rounded, so allow for it.
+        assert!((next.rect().min.x - (word_size.x + 10.)).abs() < 1.);
+    });
+}
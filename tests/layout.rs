@@ -0,0 +1,174 @@
+mod common;
+
+use common::{Probe, with_ui};
+use egui::{Pos2, Rect, Ui, Vec2, vec2};
+use egui_layout::layout::{
+    Alignment, Constraints, Draw, Flex, Layout, LayoutDirection, LayoutParams, MainAxisAlignment,
+    Measure,
+};
+
+fn row(main_axis_alignment: MainAxisAlignment, cross_axis_alignment: Alignment) -> LayoutParams {
+    LayoutParams {
+        direction: LayoutDirection::Row,
+        main_axis_alignment,
+        cross_axis_alignment,
+        gap: Some(0.),
+        ..Default::default()
+    }
+}
+
+fn show(layout: Layout, constraints: Constraints, ui: &mut Ui) -> Vec2 {
+    let (size, measured) = layout.measure(constraints, ui);
+    measured.draw(Rect::from_min_size(Pos2::ZERO, size), ui);
+    size
+}
+
+/// Draws into a region of `size` regardless of the measured size, leaving free space to align
+/// the children in.
+fn show_in(layout: Layout, size: Vec2, ui: &mut Ui) {
+    let (_, measured) = layout.measure(Constraints::loose(size), ui);
+    measured.draw(Rect::from_min_size(Pos2::ZERO, size), ui);
+}
+
+#[test]
+fn growing_children_split_the_free_space() {
+    with_ui(|ui| {
+        let children = [Probe::fixed(10., 10.), Probe::fixed(10., 10.)];
+        let layout = Layout::new(row(MainAxisAlignment::Start, Alignment::Start))
+            .with_flex_child(Flex::grow(1.), children[0].clone())
+            .with_flex_child(Flex::grow(2.), children[1].clone());
+
+        let size = show(layout, Constraints::loose(vec2(300., 100.)), ui);
+
+        assert_eq!(size, vec2(300., 10.));
+        assert_eq!(children.each_ref().map(|it| it.rect().min.x), [0., 100.]);
+        assert_eq!(
+            children.each_ref().map(|it| it.rect().width()),
+            [100., 200.]
+        );
+    });
+}
+
+#[test]
+fn children_with_a_basis_shrink_by_their_weighted_shrink_factor() {
+    with_ui(|ui| {
+        let children = [Probe::fixed(10., 10.), Probe::fixed(10., 10.)];
+        let flex = |shrink| Flex {
+            shrink,
+            basis: Some(200.),
+            ..Default::default()
+        };
+        let layout = Layout::new(row(MainAxisAlignment::Start, Alignment::Start))
+            .with_flex_child(flex(1.), children[0].clone())
+            .with_flex_child(flex(3.), children[1].clone());
+
+        show(layout, Constraints::loose(vec2(300., 100.)), ui);
+
+        assert_eq!(children[0].rect().width(), 175.);
+        assert_eq!(children[1].rect().width(), 125.);
+    });
+}
+
+#[test]
+fn main_axis_alignment_places_the_free_space() {
+    with_ui(|ui| {
+        let cases = [
+            (MainAxisAlignment::Start, [0., 50.]),
+            (MainAxisAlignment::End, [200., 250.]),
+            (MainAxisAlignment::Center, [100., 150.]),
+            (MainAxisAlignment::SpaceBetween, [0., 250.]),
+            (MainAxisAlignment::SpaceAround, [50., 200.]),
+            (MainAxisAlignment::SpaceEvenly, [200. / 3., 50. + 400. / 3.]),
+        ];
+
+        for (alignment, expected) in cases {
+            let children = [Probe::fixed(50., 10.), Probe::fixed(50., 10.)];
+            let layout = Layout::new(row(alignment, Alignment::Start))
+                .with_child(children[0].clone())
+                .with_child(children[1].clone());
+
+            show_in(layout, vec2(300., 10.), ui);
+
+            assert_eq!(children.each_ref().map(|it| it.rect().min.x), expected);
+        }
+    });
+}
+
+#[test]
+fn baseline_alignment_lines_up_the_baselines() {
+    with_ui(|ui| {
+        let children = [
+            Probe::fixed(20., 30.).with_baseline(20.),
+            Probe::fixed(20., 20.).with_baseline(10.),
+        ];
+        let layout = Layout::new(row(MainAxisAlignment::Start, Alignment::Baseline))
+            .with_child(children[0].clone())
+            .with_child(children[1].clone());
+
+        let size = show(layout, Constraints::loose(vec2(300., 100.)), ui);
+
+        assert_eq!(size, vec2(40., 30.));
+        assert_eq!(children[0].rect().min.y, 0.);
+        assert_eq!(children[1].rect().min.y, 10.);
+    });
+}
+
+#[test]
+fn gap_separates_the_children() {
+    with_ui(|ui| {
+        let children = [
+            Probe::fixed(20., 10.),
+            Probe::fixed(20., 10.),
+            Probe::fixed(20., 10.),
+        ];
+        let layout = Layout::new(LayoutParams {
+            gap: Some(10.),
+            ..row(MainAxisAlignment::Start, Alignment::Start)
+        })
+        .with_child(children[0].clone())
+        .with_child(children[1].clone())
+        .with_child(children[2].clone());
+
+        let size = show(layout, Constraints::loose(vec2(300., 100.)), ui);
+
+        assert_eq!(size, vec2(80., 10.));
+        assert_eq!(
+            children.each_ref().map(|it| it.rect().min.x),
+            [0., 30., 60.]
+        );
+    });
+}
+
+#[test]
+fn stretch_sizes_children_to_the_largest_child() {
+    with_ui(|ui| {
+        let children = [Probe::fixed(30., 20.), Probe::fixed(30., 40.)];
+        let layout = Layout::new(row(MainAxisAlignment::Start, Alignment::Stretch))
+            .with_child(children[0].clone())
+            .with_child(children[1].clone());
+
+        let size = show(layout, Constraints::loose(vec2(800., 600.)), ui);
+
+        assert_eq!(size, vec2(60., 40.));
+        assert_eq!(children[0].rect().height(), 40.);
+        assert_eq!(children[1].rect().height(), 40.);
+    });
+}
+
+#[test]
+fn stretch_fills_a_tight_cross_axis() {
+    with_ui(|ui| {
+        let child = Probe::fixed(30., 20.);
+        let layout = Layout::new(row(MainAxisAlignment::Start, Alignment::Stretch))
+            .with_child(child.clone());
+        let constraints = Constraints {
+            min: vec2(0., 100.),
+            max: vec2(800., 100.),
+        };
+
+        let size = show(layout, constraints, ui);
+
+        assert_eq!(size.y, 100.);
+        assert_eq!(child.rect().height(), 100.);
+    });
+}
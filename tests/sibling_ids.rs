@@ -0,0 +1,73 @@
+mod common;
+
+use common::with_ui;
+use egui::{LayerId, Ui, vec2};
+use egui_layout::layout::{
+    Constraints, Draw, Flex, Layout, LayoutDirection, LayoutParams, Measure, Overflow,
+};
+use egui_layout::widgets::scroll::Scroll;
+use egui_layout::widgets::text::Text;
+
+/// Id clashes are reported by painting warnings onto the debug layer.
+fn id_clash_count(ui: &Ui) -> usize {
+    ui.ctx().graphics(|it| {
+        it.get(LayerId::debug())
+            .map_or(0, |paint_list| paint_list.next_idx().0)
+    })
+}
+
+fn tall_column<'a>() -> Layout<'a> {
+    (0..50).map(|_| Text::new("row")).collect::<Layout>()
+}
+
+#[test]
+fn sibling_layouts_have_distinct_responses() {
+    with_ui(|ui| {
+        let region = ui.available_rect_before_wrap();
+        let (_, measured) =
+            Layout::new(LayoutParams::default()).measure(Constraints::loose(region.size()), ui);
+        let first = measured.draw(region.split_left_right_at_fraction(0.5).0, ui);
+        let (_, measured) =
+            Layout::new(LayoutParams::default()).measure(Constraints::loose(region.size()), ui);
+        let second = measured.draw(region.split_left_right_at_fraction(0.5).1, ui);
+
+        assert_ne!(first.id, second.id);
+        assert_eq!(first.rect, region.split_left_right_at_fraction(0.5).0);
+        assert_eq!(second.rect, region.split_left_right_at_fraction(0.5).1);
+    });
+}
+
+#[test]
+fn sibling_scrolls_dont_clash() {
+    with_ui(|ui| {
+        let (size, measured) = Layout::new(LayoutParams::default())
+            .with_flex_child(Flex::grow(1.), Scroll::vertical(tall_column()))
+            .with_flex_child(Flex::grow(1.), Scroll::vertical(tall_column()))
+            .measure(Constraints::loose(vec2(400., 200.)), ui);
+        measured.draw(ui.allocate_space(size).1, ui);
+
+        assert_eq!(id_clash_count(ui), 0);
+    });
+}
+
+#[test]
+fn sibling_scrolling_layouts_dont_clash() {
+    let scrolling_column = || {
+        Layout::new(LayoutParams {
+            direction: LayoutDirection::Column,
+            overflow: Overflow::Scroll,
+            ..Default::default()
+        })
+        .with_children((0..50).map(|_| Text::new("row")))
+    };
+
+    with_ui(|ui| {
+        let (size, measured) = Layout::new(LayoutParams::default())
+            .with_flex_child(Flex::grow(1.), scrolling_column())
+            .with_flex_child(Flex::grow(1.), scrolling_column())
+            .measure(Constraints::loose(vec2(400., 200.)), ui);
+        measured.draw(ui.allocate_space(size).1, ui);
+
+        assert_eq!(id_clash_count(ui), 0);
+    });
+}
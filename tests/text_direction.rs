@@ -0,0 +1,74 @@
+mod common;
+
+use common::{Probe, with_ui};
+use egui::{Align, Id, Pos2, Rect, Ui, vec2};
+use egui_layout::layout::{
+    Constraints, Draw, Layout, LayoutDirection, LayoutParams, Measure, TextDirection,
+};
+use egui_layout::widgets::eager::EagerMeasuredWidget;
+use egui_layout::widgets::wrap::{Wrap, WrapParams};
+use std::cell::Cell;
+
+#[test]
+fn right_aligned_egui_layouts_stay_left_to_right() {
+    with_ui(|ui| {
+        ui.with_layout(egui::Layout::right_to_left(Align::Center), |ui| {
+            assert_eq!(TextDirection::from_ui(ui), TextDirection::LeftToRight);
+        });
+        ui.with_layout(egui::Layout::top_down(Align::Max), |ui| {
+            assert_eq!(TextDirection::from_ui(ui), TextDirection::LeftToRight);
+        });
+    });
+}
+
+#[test]
+fn nested_layouts_inherit_the_text_direction() {
+    with_ui(|ui| {
+        let drawn_direction = Cell::new(None);
+        let layout = Layout::new(LayoutParams {
+            text_direction: Some(TextDirection::RightToLeft),
+            ..Default::default()
+        })
+        .with_child(Layout::new(LayoutParams::default()).with_child(
+            EagerMeasuredWidget::new(
+                |ui: &mut Ui| {
+                    drawn_direction.set(Some(TextDirection::from_ui(ui)));
+                    ui.label("child")
+                },
+                Id::new("child"),
+            ),
+        ));
+
+        let (size, measured) = layout.measure(Constraints::loose(ui.available_size()), ui);
+        measured.draw(ui.allocate_space(size).1, ui);
+
+        assert_eq!(drawn_direction.get(), Some(TextDirection::RightToLeft));
+    });
+}
+
+#[test]
+fn right_to_left_wrapped_columns_start_on_the_right() {
+    with_ui(|ui| {
+        let children = [Probe::fixed(20., 60.), Probe::fixed(20., 60.)];
+        let layout = Layout::new(LayoutParams {
+            text_direction: Some(TextDirection::RightToLeft),
+            ..Default::default()
+        })
+        .with_child(
+            Wrap::new(WrapParams {
+                direction: LayoutDirection::Column,
+                gap: Some(0.),
+                run_gap: Some(10.),
+                ..Default::default()
+            })
+            .with_child(children[0].clone())
+            .with_child(children[1].clone()),
+        );
+
+        let (size, measured) = layout.measure(Constraints::loose(vec2(300., 100.)), ui);
+        measured.draw(Rect::from_min_size(Pos2::ZERO, size), ui);
+
+        assert_eq!(size, vec2(50., 60.));
+        assert_eq!(children.each_ref().map(|it| it.rect().min.x), [30., 0.]);
+    });
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::with_ui;
+use egui::{Context, Event, PointerButton, Pos2, RawInput, Rect, Ui, vec2};
+use egui_layout::layout::{Constraints, Draw, Layout, LayoutParams, Measure};
+use egui_layout::widgets::button::Button;
+use egui_layout::widgets::tracked::{ResponseHandle, Tracked};
+
+fn buttons<'a>(first: &ResponseHandle, second: &ResponseHandle) -> Layout<'a> {
+    Layout::new(LayoutParams::default())
+        .with_child(Tracked::new(first, Button::new("first")))
+        .with_child(Tracked::new(second, Button::new("second")))
+}
+
+fn show(layout: Layout, ui: &mut Ui) {
+    let (size, measured) = layout.measure(Constraints::loose(vec2(400., 100.)), ui);
+    measured.draw(Rect::from_min_size(Pos2::ZERO, size), ui);
+}
+
+#[test]
+fn tracked_children_report_their_own_responses() {
+    with_ui(|ui| {
+        let (first, second) = (ResponseHandle::new(), ResponseHandle::new());
+
+        show(buttons(&first, &second), ui);
+
+        let (first, second) = (first.response().unwrap(), second.response().unwrap());
+        assert_ne!(first.id, second.id);
+        assert!(first.rect.right() <= second.rect.left());
+    });
+}
+
+#[test]
+fn clicks_are_reported_to_the_clicked_child() {
+    let ctx = Context::default();
+    let (first, second) = (ResponseHandle::new(), ResponseHandle::new());
+    let run = |events: Vec<Event>| {
+        let _ = ctx.run(
+            RawInput {
+                events,
+                ..Default::default()
+            },
+            |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| show(buttons(&first, &second), ui));
+            },
+        );
+    };
+
+    run(vec![]);
+    let pos = second.response().unwrap().rect.center();
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    run(vec![Event::PointerMoved(pos), button(true)]);
+    run(vec![button(false)]);
+
+    assert!(!first.clicked());
+    assert!(second.clicked());
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use common::{Probe, with_ui};
+use egui::{Pos2, Rect, Ui, Vec2, vec2};
+use egui_layout::layout::{
+    Alignment, Constraints, Draw, Flex, Layout, LayoutDirection, LayoutParams, MainAxisAlignment,
+    Measure, TypedLayout,
+};
+use egui_layout::widgets::flexible::Flexible;
+
+fn params(direction: LayoutDirection) -> LayoutParams {
+    LayoutParams {
+        direction,
+        main_axis_alignment: MainAxisAlignment::Start,
+        cross_axis_alignment: Alignment::Center,
+        gap: Some(10.),
+        ..Default::default()
+    }
+}
+
+fn show(layout: impl Measure, ui: &mut Ui) -> Vec2 {
+    let (size, measured) = layout.measure(Constraints::loose(vec2(300., 200.)), ui);
+    measured.draw(Rect::from_min_size(Pos2::ZERO, size), ui);
+    size
+}
+
+fn rects<const N: usize>(probes: &[Probe; N]) -> [Rect; N] {
+    probes.each_ref().map(|it| it.rect())
+}
+
+#[test]
+fn tuple_layout_places_children_like_a_boxed_layout() {
+    with_ui(|ui| {
+        let typed = [Probe::fixed(30., 10.), Probe::wrapping(400., 20.)];
+        let boxed = [Probe::fixed(30., 10.), Probe::wrapping(400., 20.)];
+
+        let typed_size = show(
+            TypedLayout::new(
+                params(LayoutDirection::Column),
+                (typed[0].clone(), typed[1].clone()),
+            ),
+            ui,
+        );
+        let boxed_size = show(
+            Layout::new(params(LayoutDirection::Column))
+                .with_child(boxed[0].clone())
+                .with_child(boxed[1].clone()),
+            ui,
+        );
+
+        assert_eq!(typed_size, boxed_size);
+        assert_eq!(rects(&typed), rects(&boxed));
+    });
+}
+
+#[test]
+fn array_layout_places_children_like_a_boxed_layout() {
+    with_ui(|ui| {
+        let typed = [
+            Probe::fixed(30., 10.),
+            Probe::fixed(40., 30.),
+            Probe::fixed(50., 20.),
+        ];
+        let boxed = [
+            Probe::fixed(30., 10.),
+            Probe::fixed(40., 30.),
+            Probe::fixed(50., 20.),
+        ];
+
+        let typed_size = show(
+            TypedLayout::new(params(LayoutDirection::Row), typed.clone()),
+            ui,
+        );
+        let boxed_size = show(
+            Layout::new(params(LayoutDirection::Row)).with_children(boxed.clone()),
+            ui,
+        );
+
+        assert_eq!(typed_size, vec2(140., 30.));
+        assert_eq!(typed_size, boxed_size);
+        assert_eq!(rects(&typed), rects(&boxed));
+    });
+}
+
+#[test]
+fn flexible_children_grow_like_boxed_flex_children() {
+    with_ui(|ui| {
+        let typed = [Probe::fixed(30., 10.), Probe::fixed(30., 10.)];
+        let boxed = [Probe::fixed(30., 10.), Probe::fixed(30., 10.)];
+
+        let typed_size = show(
+            TypedLayout::new(
+                params(LayoutDirection::Row),
+                (
+                    typed[0].clone(),
+                    Flexible::new(Flex::grow(1.), typed[1].clone()),
+                ),
+            ),
+            ui,
+        );
+        let boxed_size = show(
+            Layout::new(params(LayoutDirection::Row))
+                .with_child(boxed[0].clone())
+                .with_flex_child(Flex::grow(1.), boxed[1].clone()),
+            ui,
+        );
+
+        assert_eq!(typed_size, vec2(300., 10.));
+        assert_eq!(typed_size, boxed_size);
+        assert_eq!(typed[1].rect().width(), 260.);
+        assert_eq!(rects(&typed), rects(&boxed));
+    });
+}
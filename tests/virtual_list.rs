@@ -0,0 +1,25 @@
+mod common;
+
+use common::with_ui;
+use egui::{Id, vec2};
+use egui_layout::layout::{Constraints, Draw, Measure};
+use egui_layout::widgets::text::Text;
+use egui_layout::widgets::virtual_list::VirtualList;
+use std::cell::Cell;
+
+#[test]
+fn unbounded_virtual_list_builds_only_visible_rows() {
+    with_ui(|ui| {
+        let built_rows = Cell::new(0);
+        let list = VirtualList::new(Id::new("list"), 1000, |index| {
+            built_rows.set(built_rows.get() + 1);
+            Text::new(format!("row {index}"))
+        });
+
+        let (size, measured) = list.measure(Constraints::UNBOUNDED, ui);
+        measured.draw(ui.allocate_space(size).1, ui);
+
+        assert_eq!(size, vec2(200., 200.));
+        assert!(built_rows.get() < 50, "built {} rows", built_rows.get());
+    });
+}